@@ -2,26 +2,531 @@ use std::fs;
 use std::fs::{File, read_dir};
 use std::io;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::cell::OnceCell;
 use quote::quote;
 use proc_macro2::{TokenStream, Ident, Span};
+use leptos::{component, view, IntoView, CollectView};
+
+// shared with the app, that only queries the index
+#[allow(dead_code)]
+#[path = "src/search_index.rs"]
+mod search_index;
+
+#[path = "build/analysis.rs"]
+mod analysis;
+use analysis::{
+    Marker, notes, Diagnostic, ApiKind, APIS, used_apis, documented_items,
+    expand_wiki_links, check_markers, check_signal_calls,
+};
 
 use serde::Deserialize;
+use serde::de::DeserializeOwned;
 
 use syntect::{
     highlighting::ThemeSet, 
-    html::highlighted_html_for_string, 
-    parsing::SyntaxSet
+    html::{ClassedHTMLGenerator, ClassStyle, css_for_theme_with_class_style, line_tokens_to_classed_spans},
+    parsing::{ParseState, ScopeStack, SyntaxSet},
+    util::LinesWithEndings,
 };
 
-/// pre-process a code snippet to add html 
-/// syntax-highlighting
-fn highlight(code: &str) -> String {
-    let ps = SyntaxSet::load_defaults_newlines();
+/// the css classes of the highlighted code are not prefixed, to keep the html small.
+/// The stylesheets only apply them inside the `<pre class="code">`
+const CLASS_STYLE: ClassStyle = ClassStyle::Spaced;
+
+/// the code themes the reader can choose from,
+/// and the syntect theme used for each one
+const THEMES: [(&str, &str); 2] = [
+    ("light", "base16-ocean.light"),
+    ("dark", "base16-ocean.dark"),
+];
+
+/// the syntax definitions syntect does not have, like toml
+const SYNTAXES_DIR: &str = "syntaxes";
+
+/// the syntaxes of `SYNTAXES_DIR`, in a set of their own:
+/// adding them to the precompiled ones of syntect would compile all of them again,
+/// which takes most of the time of the build script
+fn load_extra_syntaxes() -> SyntaxSet {
+    SyntaxSet::load_from_folder(SYNTAXES_DIR)
+        .unwrap_or_else(|e| panic!("invalid syntax definition in `{SYNTAXES_DIR}`: {e}"))
+}
+
+/// a hash of the files of `SYNTAXES_DIR`, for the keys of the highlighting cache
+fn hash_extra_syntaxes() -> u64 {
+    let mut hasher = DefaultHasher::new();
+    let mut files: Vec<_> = read_dir(SYNTAXES_DIR)
+        .map(|dir| dir.filter_map(|e| e.ok()).map(|e| e.path()).collect())
+        .unwrap_or_default();
+    files.sort();
+    for file in files {
+        file.hash(&mut hasher);
+        fs::read(&file).ok().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// tells cargo to run the build script again when `path` changes.
+/// Without it, any change in the package would rerun it
+fn watch(path: &Path) {
+    println!("cargo:rerun-if-changed={}", path.display());
+}
+
+/// pre-process code snippets to add html 
+/// syntax-highlighting.
+/// The colors are given by the stylesheets of `themes/`.
+/// Every line is wrapped in a `<span class="code-line" id="L{n}">`,
+/// a class that syntect does not use for its own spans,
+/// with an empty `line-number` element for the gutter.
+/// The marker comments are not shown: hidden lines are folded in a `<details>`,
+/// and the line after `lbe:focus` gets the `focus` class.
+/// The comment lines of a `// NOTE:` get the `note-source` class,
+/// and the note is also rendered as an `<aside class="note">`
+/// right after the line it is about.
+/// The leptos items are links to their docs, see `DocsLinks`.
+///
+/// syntect's syntaxes are loaded at most once per build,
+/// and the html of each snippet is cached in `OUT_DIR`,
+/// so that the unchanged examples are not highlighted again
+struct Highlighter {
+    /// the precompiled syntaxes of syntect
+    syntaxes: OnceCell<SyntaxSet>,
+    /// only loaded for a snippet in a language syntect does not know
+    extra_syntaxes: OnceCell<SyntaxSet>,
+    extra_syntaxes_hash: u64,
+    cache_dir: Option<PathBuf>,
+    docs: DocsLinks,
+}
+
+impl Highlighter {
+    fn new(docs: DocsLinks) -> Self {
+        let cache_dir = std::env::var_os("OUT_DIR")
+            .map(|d| PathBuf::from(d).join("highlight-cache"))
+            .filter(|d| fs::create_dir_all(d).is_ok());
+        Highlighter {
+            syntaxes: OnceCell::new(),
+            extra_syntaxes: OnceCell::new(),
+            extra_syntaxes_hash: hash_extra_syntaxes(),
+            cache_dir,
+            docs,
+        }
+    }
+
+    /// the cache entry of a snippet.
+    /// The key also depends on this build script, on the syntaxes of `SYNTAXES_DIR`
+    /// and on the versions of the docs, so that changing the highlighting invalidates the cache
+    fn cache_path(&self, code: &str) -> Option<PathBuf> {
+        let mut hasher = DefaultHasher::new();
+        include_str!("build.rs").hash(&mut hasher);
+        self.extra_syntaxes_hash.hash(&mut hasher);
+        self.docs.hash(&mut hasher);
+        code.hash(&mut hasher);
+        let key = hasher.finish();
+        self.cache_dir.as_ref().map(|d| d.join(format!("{key:016x}.html")))
+    }
+
+    /// a code block of a markdown field, in any language syntect knows.
+    /// Unlike the examples, it has no line numbers and no links
+    fn highlight_snippet(&self, code: &str, language: &str) -> Option<String> {
+        let cache_path = self.cache_path(&format!("{language}\n{code}"));
+        if let Some(html) = cache_path.as_ref().and_then(|p| fs::read_to_string(p).ok()) {
+            return Some(html);
+        }
+
+        let defaults = self.syntaxes.get_or_init(SyntaxSet::load_defaults_newlines);
+        let (ps, syntax) = match defaults.find_syntax_by_token(language) {
+            Some(syntax) => (defaults, syntax),
+            None => {
+                let extra = self.extra_syntaxes.get_or_init(load_extra_syntaxes);
+                (extra, extra.find_syntax_by_token(language)?)
+            },
+        };
+        let mut generator = ClassedHTMLGenerator::new_with_class_style(syntax, ps, CLASS_STYLE);
+        for line in LinesWithEndings::from(code) {
+            generator.parse_html_for_line_which_includes_newline(line).ok()?;
+        }
+        let html = format!("<pre class=\"code\">{}</pre>", generator.finalize());
+
+        if let Some(p) = cache_path {
+            let _ = fs::write(p, &html);
+        }
+        Some(html)
+    }
+
+    fn highlight(&self, code: &str) -> String {
+        let cache_path = self.cache_path(code);
+        if let Some(html) = cache_path.as_ref().and_then(|p| fs::read_to_string(p).ok()) {
+            return html;
+        }
+
+        let ps = self.syntaxes.get_or_init(SyntaxSet::load_defaults_newlines);
+        let syntax = ps.find_syntax_by_extension("rs").unwrap();
+        let mut state = ParseState::new(syntax);
+        let mut stack = ScopeStack::new();
+        let mut html = String::from("<pre class=\"code\">");
+        let mut focus = false;
+        let notes = notes(code);
+        let links: HashMap<_, _> = documented_items(syn::parse_file(code).ok().as_ref())
+            .into_iter()
+            .map(|(name, (krate, kind))| {
+                let link = self.docs.link(krate, &name, kind);
+                (name, link)
+            })
+            .collect();
+        for (i, line) in LinesWithEndings::from(code).enumerate() {
+            let ops = state.parse_line(line, ps).unwrap();
+            // each line is a standalone element, so the scopes still open
+            // from the previous lines are opened again, and closed at its end
+            let reopened: String = stack.as_slice().iter()
+                .map(|scope| format!("<span class=\"{}\">", scope.build_string().replace('.', " ")))
+                .collect();
+            let open = stack.len() as isize;
+            let (spans, delta) = line_tokens_to_classed_spans(line, &ops, CLASS_STYLE, &mut stack).unwrap();
+            match Marker::of_line(line) {
+                Some(Marker::HideStart) => {
+                    html.push_str("<details class=\"fold\"><summary>…</summary>");
+                    continue
+                },
+                Some(Marker::HideEnd) => {
+                    html.push_str("</details>");
+                    continue
+                },
+                Some(Marker::Focus) => {
+                    focus = true;
+                    continue
+                },
+                None => (),
+            }
+            let mut class = String::from("code-line");
+            if std::mem::take(&mut focus) {
+                class.push_str(" focus");
+            }
+            if notes.iter().any(|note| note.comment.contains(&i)) {
+                class.push_str(" note-source");
+            }
+            let n = i + 1;
+            let code = format!(
+                "{reopened}{}{}",
+                spans.replacen('\n', "", 1),
+                "</span>".repeat((open + delta) as usize),
+            );
+            html.push_str(&format!(
+                "<span class=\"{class}\" id=\"L{n}\"><span class=\"line-number\" data-line=\"{n}\"></span>{}</span>",
+                link_items(&code, &links),
+            ));
+            for note in notes.iter().filter(|note| note.line == i) {
+                html.push_str("<aside class=\"note\">");
+                pulldown_cmark::html::push_html(&mut html, pulldown_cmark::Parser::new(&note.markdown));
+                html.push_str("</aside>");
+            }
+        }
+        html.push_str("</pre>");
+
+        // the cache is only an optimization, failing to write it is fine
+        if let Some(p) = cache_path {
+            let _ = fs::write(p, &html);
+        }
+        html
+    }
+}
+
+/// restricts every selector of a syntect stylesheet
+/// to the highlighted code inside `scope`
+fn scope_css(css: &str, scope: &str) -> String {
+    css.lines()
+        .map(|line| match line.strip_suffix(" {") {
+            Some(selectors) => {
+                let selectors: Vec<_> = selectors.split(", ")
+                    .map(|s| match s {
+                        ".code" => format!("{scope} .code"),
+                        _ => format!("{scope} .code {s}"),
+                    })
+                    .collect();
+                format!("{} {{", selectors.join(", "))
+            },
+            None => line.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// writes `contents` to `file`, unless it already has them:
+/// rewriting an unchanged file would make trunk copy it again, and reload the page
+fn write_if_changed(file: &Path, contents: &str) -> Result<(), io::Error> {
+    if fs::read_to_string(file).ok().as_deref() != Some(contents) {
+        fs::write(file, contents)?;
+    }
+    Ok(())
+}
+
+/// writes `themes/{name}.css` for each theme.
+/// Its rules only apply inside an element with the `theme-{name}` class,
+/// so the app can switch between them
+fn write_theme_stylesheets() -> Result<(), io::Error> {
     let ts = ThemeSet::load_defaults();
-    let syntax = ps.find_syntax_by_extension("rs").unwrap();
-    let theme = &ts.themes["base16-ocean.light"];
-    highlighted_html_for_string(code, &ps, syntax, &theme).unwrap()
+    fs::create_dir_all("themes")?;
+    for (name, theme) in THEMES {
+        let css = css_for_theme_with_class_style(&ts.themes[theme], CLASS_STYLE)
+            .map_err(io::Error::other)?;
+        write_if_changed(Path::new(&format!("themes/{name}.css")), &scope_css(&css, &format!(".theme-{name}")))?;
+    }
+    Ok(())
+}
+
+/// where the highlighted html of the examples is written.
+/// Trunk copies it next to the website,
+/// and the app only fetches a file when it is shown
+const HIGHLIGHTED_DIR: &str = "highlighted";
+
+/// writes the highlighted html of the file `path` of an example,
+/// and returns its url, relative to the website
+fn write_highlighted(example: &str, path: &str, html: &str) -> Result<String, io::Error> {
+    let url = format!("{HIGHLIGHTED_DIR}/{example}/{path}.html");
+    let file = Path::new(&url);
+    fs::create_dir_all(file.parent().unwrap())?;
+    write_if_changed(file, html)?;
+    Ok(url)
+}
+
+/// where the pre-rendered page of each example is written.
+/// Trunk copies it next to the website, so that search engines
+/// and readers without javascript see more than an empty `<body>`
+const PAGES_DIR: &str = "pages";
+
+/// the page of `PAGES_DIR` that links to all the others, so that they can be found
+const PAGES_INDEX: &str = "index";
+
+/// what the pre-rendered page of an example shows
+struct Page {
+    name: String,
+    description: String,
+    motivation: String,
+    related: Option<String>,
+    links: Vec<String>,
+    /// the path of each file, as shown in its tab, and its highlighted html
+    files: Vec<(String, String)>,
+}
+
+/// `html` where the links into the app, `#foo` or `#foo:model.rs:L4`,
+/// lead to the pre-rendered page of `foo` instead.
+/// The API index has no page: its links stay in the app
+fn static_links(html: &str) -> String {
+    let mut linked = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find("href=\"#") {
+        let start = start + "href=\"".len();
+        linked.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = rest.find('"').unwrap_or(rest.len());
+        let name = rest[1..end].split(':').next().unwrap_or_default();
+        if name != API_INDEX {
+            linked.push_str(&format!("{PAGES_DIR}/{name}.html"));
+            rest = &rest[end..];
+        }
+    }
+    linked.push_str(rest);
+    linked
+}
+
+/// the same markup as the app, so that `style.css` applies.
+/// All the files are on the same page: the ids of their lines start with their path
+#[component]
+fn StaticExample(page: Page) -> impl IntoView {
+    let Page { name, description, motivation, related, links, files } = page;
+    view!{
+        <h1 class="title">Leptos by example</h1>
+        <div class="layout theme-light">
+            <div class="container">
+                <div class="example-title">
+                    <b>{name}</b>
+                </div>
+                {files.into_iter()
+                    .map(|(path, html)| view!{
+                        <div class="code-snippet">
+                            <h3><code>{path.clone()}</code></h3>
+                            <div inner_html=html.replace(" id=\"L", &format!(" id=\"{path}-L"))></div>
+                        </div>
+                    })
+                    .collect_view()
+                }
+                <div class="description">
+                    <h3>What</h3>
+                    <pre>{description}</pre>
+                    <h3>Why</h3>
+                    <div inner_html=static_links(&motivation)></div>
+                    <h3>See also</h3>
+                    <div inner_html=related.as_deref().map(static_links)></div>
+                    <ul>
+                        {links.into_iter()
+                            .map(|l| view!{<li><a href=format!("{PAGES_DIR}/{l}.html")>{l}</a></li>})
+                            .collect_view()
+                        }
+                    </ul>
+                    <a href=format!("{PAGES_DIR}/{PAGES_INDEX}.html")>"All the examples"</a>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn StaticIndex(names: Vec<String>) -> impl IntoView {
+    view!{
+        <h1 class="title">Leptos by example</h1>
+        <ul>
+            {names.into_iter()
+                .map(|n| view!{<li><a href=format!("{PAGES_DIR}/{n}.html")>{n}</a></li>})
+                .collect_view()
+            }
+        </ul>
+    }
+}
+
+/// a page of `PAGES_DIR` around its pre-rendered `body`.
+/// Its `<base>` is the root of the website,
+/// so the links of the descriptions and the app's files work unchanged.
+/// The files of the app are linked by their names without the hashes trunk adds,
+/// the `post_build` hook of `Trunk.toml` renames them.
+/// The wasm bundle loaded at the end replaces the static content by the live app
+/// at the `example`, see `take_over_prerendered` in `main.rs`
+fn page_document(title: &str, description: &str, example: &str, body: &str) -> String {
+    let escape = |text: &str| {
+        let mut escaped = String::new();
+        pulldown_cmark::escape::escape_html(&mut escaped, text).unwrap();
+        escaped
+    };
+    let (title, description, example) = (escape(title), escape(description.trim()), escape(example));
+    format!(r#"<!DOCTYPE html>
+<html>
+    <head>
+        <meta charset="utf-8">
+        <base href="../">
+        <title>{title}</title>
+        <meta name="description" content="{description}">
+        <link rel="stylesheet" href="./style.css">
+        <link rel="stylesheet" href="./light.css">
+        <link rel="stylesheet" href="./dark.css">
+    </head>
+    <body>
+        <div id="prerendered" data-example="{example}">{body}</div>
+        <script type="module">
+            import init from "./leptos-by-example.js";
+            init("./leptos-by-example_bg.wasm");
+        </script>
+    </body>
+</html>
+"#)
+}
+
+/// renders the page of an example with leptos' server-side rendering
+fn render_page(page: Page) -> String {
+    let title = format!("{} - Leptos by example", page.name);
+    let (name, description) = (page.name.clone(), page.description.clone());
+    let body = leptos::ssr::render_to_string(move || view!{<StaticExample page=page/>});
+    page_document(&title, &description, &name, &body)
+}
+
+/// writes the page that links to the pages of the `names` examples.
+/// The app opens its default example
+fn write_pages_index(names: &[String]) -> Result<(), io::Error> {
+    fs::create_dir_all(PAGES_DIR)?;
+    let names = names.to_vec();
+    let body = leptos::ssr::render_to_string(move || view!{<StaticIndex names=names/>});
+    let html = page_document("Leptos by example", "Every example of Leptos by example", "", &body);
+    write_if_changed(&Path::new(PAGES_DIR).join(format!("{PAGES_INDEX}.html")), &html)
+}
+
+/// writes the pre-rendered page of an example, only if it changed
+fn write_page(page: Page) -> Result<(), io::Error> {
+    fs::create_dir_all(PAGES_DIR)?;
+    let file = Path::new(PAGES_DIR).join(format!("{}.html", page.name));
+    let html = render_page(page);
+    write_if_changed(&file, &html)
+}
+
+/// all the diagnostics of the build, grouped by file when printed
+#[derive(Default)]
+struct Report(Vec<Diagnostic>);
+
+impl Report {
+    fn push(&mut self, diagnostic: Diagnostic) {
+        self.0.push(diagnostic)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// prints every diagnostic, grouped by file, on stderr
+    fn print(&self) {
+        let mut by_file: BTreeMap<&str, Vec<&Diagnostic>> = BTreeMap::new();
+        for d in &self.0 {
+            by_file.entry(&d.file).or_default().push(d);
+        }
+
+        eprintln!("found {} problem(s) in the examples:", self.0.len());
+        for (file, diagnostics) in by_file {
+            eprintln!();
+            eprintln!("{file}:");
+            for d in diagnostics {
+                let position = match d.position {
+                    Some((line, column)) => format!("{line}:{column}: "),
+                    None => String::new(),
+                };
+                let field = match &d.field {
+                    Some(f) => format!("field `{f}`: "),
+                    None => String::new(),
+                };
+                eprintln!("  {position}{field}{}", d.message);
+            }
+        }
+    }
+}
+
+/// line and column (starting at 1) of a byte offset in `text`
+fn line_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i+1);
+    let line = before.matches('\n').count() + 1;
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+/// the byte offset in the toml `text` where the string of `field` starts,
+/// after its opening quotes. Offsets in the string are the same in the file
+/// as long as it has no escapes
+fn field_start(text: &str, field: &str) -> Option<usize> {
+    let table: BTreeMap<String, toml::Spanned<toml::Value>> = toml::from_str(text).ok()?;
+    let span = table.get(field)?.span();
+    let value = &text[span.clone()];
+    let quotes = ["\"\"\"", "'''", "\"", "'"].into_iter().find(|q| value.starts_with(q))?;
+    let mut start = span.start + quotes.len();
+    // the newline right after the opening quotes of a multi-line string is not part of it
+    if quotes.len() == 3 {
+        start += ["\r\n", "\n"].into_iter().find(|n| text[start..].starts_with(n)).map_or(0, str::len);
+    }
+    Some(start)
+}
+
+/// guess which field a toml error is about:
+/// either it is named in the message (missing or unknown field),
+/// or it is the key at the beginning of the line the error points to
+fn field_of_toml_error(text: &str, error: &toml::de::Error) -> Option<String> {
+    let message = error.message();
+    for prefix in ["missing field `", "unknown field `"] {
+        if let Some(rest) = message.strip_prefix(prefix) {
+            return rest.split('`').next().map(str::to_string);
+        }
+    }
+    let offset = error.span()?.start.min(text.len());
+    let line_start = text[..offset].rfind('\n').map_or(0, |i| i+1);
+    let (key, _) = text[line_start..].split_once('=')?;
+    let key = key.trim();
+    (!key.is_empty() && !key.contains(char::is_whitespace))
+        .then(|| key.to_string())
 }
 
 /// the `example.toml` representation
@@ -30,13 +535,386 @@ struct Info {
     description: String,
     motivation: String,
     related: Option<String>,
+    /// the leptos features needed, except `csr`
+    #[serde(default)]
+    features: Vec<String>,
+    /// the other crates needed, as named in `Cargo.toml`
+    #[serde(default)]
+    dependencies: Vec<String>,
+    /// the features the example needs from some of its `dependencies`.
+    /// Not the ones of the website, that also enables some for its own code
+    #[serde(default)]
+    dependency_features: BTreeMap<String, Vec<String>>,
+    /// names of related examples
+    #[serde(default)]
+    links: Vec<String>,
+    /// one of the categories of `examples/categories.toml`
+    category: Option<String>,
+    /// some of the tags of `examples/categories.toml`
+    #[serde(default)]
+    tags: Vec<String>,
+    difficulty: Option<Difficulty>,
+    /// a work in progress, only built with `LBE_DRAFTS=1`
+    #[serde(default)]
+    draft: bool,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+enum Difficulty {
+    Beginner,
+    Intermediate,
+    Advanced,
+}
+
+impl Difficulty {
+    fn quote(self) -> TokenStream {
+        match self {
+            Difficulty::Beginner => quote!{Difficulty::Beginner},
+            Difficulty::Intermediate => quote!{Difficulty::Intermediate},
+            Difficulty::Advanced => quote!{Difficulty::Advanced},
+        }
+    }
 }
 
-fn extract_toml_info(file_name: &str) -> std::result::Result<Info, toml::de::Error> {
-    let raw_toml_info = fs::read(format!("examples/{file_name}.toml"))
-        .expect("please create examples/{file_name}.toml to provide some documentation");
+impl ApiKind {
+    fn quote(self) -> TokenStream {
+        match self {
+            ApiKind::Function => quote!{ApiKind::Function},
+            ApiKind::Struct => quote!{ApiKind::Struct},
+            ApiKind::Enum => quote!{ApiKind::Enum},
+            ApiKind::TypeAlias => quote!{ApiKind::TypeAlias},
+            ApiKind::Trait => quote!{ApiKind::Trait},
+            ApiKind::Macro => quote!{ApiKind::Macro},
+            ApiKind::Attribute => quote!{ApiKind::Attribute},
+            ApiKind::Component => quote!{ApiKind::Component},
+        }
+    }
+}
+
+#[derive(Hash)]
+struct DocsLinks {
+    /// of the dependencies of the website in `Cargo.lock`, by crate name.
+    /// Other packages may depend on other versions of the same crates
+    versions: BTreeMap<String, String>,
+}
+
+impl DocsLinks {
+    fn read() -> Self {
+        watch(Path::new("Cargo.lock"));
+        let lock: toml::Table = fs::read_to_string("Cargo.lock")
+            .ok()
+            .and_then(|lock| toml::from_str(&lock).ok())
+            .unwrap_or_default();
+        let packages = match lock.get("package") {
+            Some(toml::Value::Array(packages)) => packages.as_slice(),
+            _ => &[],
+        };
+        let field = |package: &'_ toml::Value, key: &str| package.get(key).and_then(|v| v.as_str()).map(str::to_string);
+        let website = std::env::var("CARGO_PKG_NAME").ok();
+        let dependencies = packages.iter()
+            .find(|p| field(p, "name") == website)
+            .and_then(|p| p.get("dependencies"))
+            .and_then(|d| d.as_array())
+            .map(|d| d.iter().filter_map(|d| d.as_str()).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let mut versions = BTreeMap::new();
+        for dependency in dependencies {
+            // `name`, or `name version` when the lock file has several versions of the crate
+            let mut words = dependency.split(' ');
+            let Some(name) = words.next() else { continue };
+            let version = words.next().map(str::to_string).or_else(|| packages.iter()
+                .find(|p| field(p, "name").as_deref() == Some(name))
+                .and_then(|p| field(p, "version"))
+            );
+            if let Some(version) = version {
+                versions.insert(name.replace('-', "_"), version);
+            }
+        }
+        DocsLinks { versions }
+    }
+
+    /// the opening `<a>` of a link to `krate::name`.
+    /// Without its kind, the link searches the item in the docs of the crate
+    fn link(&self, krate: &str, name: &str, kind: Option<ApiKind>) -> String {
+        let version = self.versions.get(krate).map_or("latest", |v| v.as_str());
+        let page = match kind {
+            Some(kind) => format!("{}.{name}.html", kind.rustdoc_prefix()),
+            None => format!("?search={name}"),
+        };
+        let kind = kind.map_or("item", ApiKind::name);
+        format!(
+            "<a class=\"doc-link\" href=\"https://docs.rs/{krate}/{version}/{krate}/{page}\" \
+             target=\"_blank\" rel=\"noopener\" title=\"{kind} {krate}::{name}\">"
+        )
+    }
+}
+
+/// wraps the identifiers of a highlighted line that are in `links`
+/// in the corresponding link, except in comments and strings
+fn link_items(html: &str, links: &HashMap<String, String>) -> String {
+    let mut linked = String::with_capacity(html.len());
+    // whether each open span is a comment or a string
+    let mut quoted = Vec::new();
+    let mut rest = html;
+    while let Some(c) = rest.chars().next() {
+        let len = if rest.starts_with("</") {
+            quoted.pop();
+            rest.find('>').unwrap() + 1
+        } else if c == '<' {
+            let len = rest.find('>').unwrap() + 1;
+            let tag = &rest[..len];
+            quoted.push(tag.contains("comment") || tag.contains("string"));
+            len
+        } else if c == '&' {
+            rest.find(';').unwrap() + 1
+        } else if c.is_alphanumeric() || c == '_' {
+            let len = rest.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(rest.len());
+            let word = &rest[..len];
+            match links.get(word) {
+                Some(link) if !quoted.contains(&true) => {
+                    linked.push_str(&format!("{link}{word}</a>"));
+                    rest = &rest[len..];
+                    continue
+                },
+                _ => len,
+            }
+        } else {
+            c.len_utf8()
+        };
+        linked.push_str(&rest[..len]);
+        rest = &rest[len..];
+    }
+    linked
+}
+
+/// the file that declares the categories and tags
+/// the examples can use
+const CATEGORIES_FILE: &str = "categories.toml";
+
+/// the `examples/categories.toml` representation
+#[derive(Debug, Deserialize, Default)]
+struct Categories {
+    #[serde(default)]
+    tags: Vec<String>,
+    /// in the order of the sidebar
+    #[serde(default, rename = "category")]
+    categories: Vec<CategoryInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CategoryInfo {
+    name: String,
+    title: String,
+}
+
+/// the file that declares the learning path
+const PATH_FILE: &str = "path.toml";
+
+/// the `examples/path.toml` representation
+#[derive(Debug, Deserialize, Default)]
+struct LearningPath {
+    /// the names of the examples, in the order a beginner should read them
+    #[serde(default)]
+    lessons: Vec<String>,
+}
+
+/// checks that every lesson of the path is an example, and only once
+fn check_learning_path(path: &LearningPath, names: &[String]) -> Vec<Diagnostic> {
+    let file = format!("examples/{PATH_FILE}");
+    let mut errors = Vec::new();
+    for (i, lesson) in path.lessons.iter().enumerate() {
+        if !names.contains(lesson) {
+            errors.push(
+                Diagnostic::new(&file, format!("there is no example named `{lesson}`"))
+                    .in_field("lessons")
+            );
+        }
+        if path.lessons[..i].contains(lesson) {
+            errors.push(
+                Diagnostic::new(&file, format!("`{lesson}` is listed twice"))
+                    .in_field("lessons")
+            );
+        }
+    }
+    errors
+}
+
+/// checks that the category and the tags of an example are declared
+fn check_classification(path: &str, info: &Info, categories: &Categories) -> Vec<Diagnostic> {
+    let mut errors = Vec::new();
+    if let Some(category) = &info.category {
+        if !categories.categories.iter().any(|c| &c.name==category) {
+            errors.push(
+                Diagnostic::new(path, format!("unknown category `{category}`, declare it in examples/{CATEGORIES_FILE}"))
+                    .in_field("category")
+            );
+        }
+    }
+    for tag in &info.tags {
+        if !categories.tags.contains(tag) {
+            errors.push(
+                Diagnostic::new(path, format!("unknown tag `{tag}`, declare it in examples/{CATEGORIES_FILE}"))
+                    .in_field("tags")
+            );
+        }
+    }
+    errors
+}
+
+/// reads a toml file that the contributors may not have written yet
+fn read_optional_toml<T: DeserializeOwned + Default>(path: &Path, report: &mut Report) -> T {
+    if !path.is_file() {
+        return T::default()
+    }
+    read_toml(path).unwrap_or_else(|d| {
+        report.push(d);
+        T::default()
+    })
+}
+
+fn read_toml<T: DeserializeOwned>(path: &Path) -> Result<T, Diagnostic> {
+    watch(path);
+    let path = path.display().to_string();
+    let raw_toml_info = fs::read(&path)
+        .map_err(|e| Diagnostic::new(&path, format!("cannot read the file: {e}")))?;
     let toml_info = String::from_utf8_lossy(&raw_toml_info);
     toml::from_str(&toml_info)
+        .map_err(|e| Diagnostic {
+            file: path,
+            field: field_of_toml_error(&toml_info, &e),
+            position: e.span().map(|s| line_column(&toml_info, s.start)),
+            message: e.message().to_string(),
+        })
+}
+
+/// the website's `Cargo.toml`.
+/// The examples are compiled with its `[dependencies]`,
+/// so the versions shown to the reader come from there
+fn read_manifest() -> Result<toml::Table, io::Error> {
+    watch(Path::new("Cargo.toml"));
+    toml::from_str(&fs::read_to_string("Cargo.toml")?)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// a table of the manifest, like `[dependencies]` or `[features]`
+fn manifest_table(manifest: &toml::Table, name: &str) -> toml::Table {
+    match manifest.get(name) {
+        Some(toml::Value::Table(t)) => t.clone(),
+        _ => toml::Table::new(),
+    }
+}
+
+/// the cargo feature that enables every example.
+/// Each example also has its own feature, named after it
+const ALL_EXAMPLES_FEATURE: &str = "all-examples";
+
+/// whether the cargo feature of the example is enabled
+fn example_enabled(name: &str) -> bool {
+    std::env::var_os(format!("CARGO_FEATURE_{}", name.to_uppercase())).is_some()
+}
+
+/// checks that `Cargo.toml` declares the feature of the example,
+/// that it enables the optional dependencies of the example,
+/// and that it is part of `all-examples`
+fn check_feature(name: &str, info: &Info, dependencies: &toml::Table, features: &toml::Table) -> Vec<Diagnostic> {
+    let enabled_by = |feature: &str| -> Vec<String> {
+        features.get(feature)
+            .and_then(|f| f.as_array())
+            .map(|a| a.iter().filter_map(|v| v.as_str()).map(str::to_string).collect())
+            .unwrap_or_default()
+    };
+    let diagnostic = |message: String| Diagnostic::new("Cargo.toml", message).in_field("features");
+
+    let optional: Vec<_> = info.dependencies.iter()
+        .filter_map(|d| {
+            let (key, _) = manifest_dependency(dependencies, d)?;
+            let optional = dependencies.get(&key)?.get("optional")?.as_bool()?;
+            optional.then_some(key)
+        })
+        .collect();
+
+    let mut errors = Vec::new();
+    if features.contains_key(name) {
+        let enabled = enabled_by(name);
+        for d in &optional {
+            if !enabled.iter().any(|f| *f == format!("dep:{d}") || f == d) {
+                errors.push(diagnostic(format!("the feature `{name}` must enable `dep:{d}`, a dependency of the example")));
+            }
+        }
+    } else {
+        let enabled: Vec<_> = optional.iter().map(|d| format!("\"dep:{d}\"")).collect();
+        errors.push(diagnostic(format!("the example `{name}` needs a feature, add `{name} = [{}]`", enabled.join(", "))));
+    }
+    if !enabled_by(ALL_EXAMPLES_FEATURE).iter().any(|f| f == name) {
+        errors.push(diagnostic(format!("`{ALL_EXAMPLES_FEATURE}` must enable the feature `{name}`")));
+    }
+    errors
+}
+
+fn dependency_line(name: &str, version: &str, features: &[String]) -> String {
+    if features.is_empty() {
+        return format!("{name} = \"{version}\"");
+    }
+    let features: Vec<_> = features.iter()
+        .map(|f| format!("\"{f}\""))
+        .collect();
+    format!("{name} = {{ version = \"{version}\", features = [{}] }}", features.join(", "))
+}
+
+/// key and version of a dependency of the website
+fn manifest_dependency(dependencies: &toml::Table, name: &str) -> Option<(String, String)> {
+    let (key, value) = [name.to_string(), name.replace('_', "-")]
+        .into_iter()
+        .find_map(|k| dependencies.get(&k).map(|v| (k, v)))?;
+    let version = match value {
+        toml::Value::String(v) => v.clone(),
+        toml::Value::Table(t) => t.get("version")?.as_str()?.to_string(),
+        _ => return None,
+    };
+    Some((key, version))
+}
+
+/// the lines a reader has to add to the `[dependencies]`
+/// of their own `Cargo.toml` to run an example
+fn cargo_setup(path: &str, info: &Info, dependencies: &toml::Table) -> Result<String, Vec<Diagnostic>> {
+    let mut errors = Vec::new();
+    let mut lines = Vec::new();
+
+    let leptos_version = manifest_dependency(dependencies, "leptos")
+        .map(|(_, v)| v)
+        .unwrap_or_else(|| "*".to_string());
+    let mut leptos_features = vec!["csr".to_string()];
+    for f in &info.features {
+        if !leptos_features.contains(f) {
+            leptos_features.push(f.clone());
+        }
+    }
+    lines.push(dependency_line("leptos", &leptos_version, &leptos_features));
+
+    for name in info.dependency_features.keys().filter(|name| !info.dependencies.contains(name)) {
+        errors.push(
+            Diagnostic::new(path, format!("`{name}` has features but is not in `dependencies`"))
+                .in_field("dependency_features")
+        );
+    }
+    for name in info.dependencies.iter().filter(|x| *x!="leptos") {
+        let features = info.dependency_features.get(name).map_or(&[][..], Vec::as_slice);
+        match manifest_dependency(dependencies, name) {
+            Some((key, version)) => lines.push(dependency_line(&key, &version, features)),
+            None => errors.push(
+                Diagnostic::new(path, format!("`{name}` is not a dependency of the website, add it to Cargo.toml"))
+                    .in_field("dependencies")
+            ),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(lines.join("\n"))
+    } else {
+        Err(errors)
+    }
 }
 
 fn quote_option(text: Option<String>) -> TokenStream {
@@ -46,102 +924,683 @@ fn quote_option(text: Option<String>) -> TokenStream {
     }
 }
 
-fn html_from_markdown(file_name: &str, input: String) -> String {
-    use pulldown_cmark::{Tag, Event};
-    let parser = pulldown_cmark::Parser::new(&input);
+/// the url of the API index page, see `src/api.rs`
+const API_INDEX: &str = "api-index";
 
-    let checked_parser = parser
-        .map(|x| match x {
-            Event::Start(Tag::Heading(_,_,_)) => panic!(
-                "{file_name}.toml: headings are not allowed in this field"
-            ),
-            _ => x,
-    });
+/// what the markdown fields can link to, and how they are highlighted
+struct Markdown<'a> {
+    /// the description of every example
+    descriptions: BTreeMap<String, String>,
+    /// the leptos items in the API index
+    api_usages: &'a BTreeMap<&'static str, Vec<String>>,
+    highlighter: &'a Highlighter,
+}
+
+/// the leptos item an inline code span is about, if it is in the API index:
+/// `create_signal`, `create_signal()`, `view!`, `<For/>`, `leptos::For`...
+fn api_of_code<'a>(code: &str, api_usages: &'a BTreeMap<&'static str, Vec<String>>) -> Option<&'a str> {
+    let name = code.trim()
+        .trim_start_matches('<')
+        .trim_end_matches('>')
+        .trim_end_matches('/')
+        .trim_end_matches("()")
+        .trim_end_matches('!')
+        .rsplit("::")
+        .next()?;
+    api_usages.get_key_value(name).map(|(name, _)| *name)
+}
+
+/// renders a markdown field of `path`.
+/// The links to other examples, `#foo`, `./foo` or `[[foo]]`,
+/// are checked against the `descriptions`, that has every example.
+/// The fenced code blocks are highlighted,
+/// and the inline code about a leptos item links to the API index
+fn html_from_markdown(path: &str,
+                      field: &str,
+                      input: String,
+                      markdown: &Markdown) -> Result<String, Vec<Diagnostic>> {
+    use pulldown_cmark::{Tag, Event, CodeBlockKind};
+    let descriptions = &markdown.descriptions;
+
+    // where an offset of the field is in the toml file
+    let toml = fs::read_to_string(path).unwrap_or_default();
+    let start = field_start(&toml, field);
+    let diagnostic = |offset: usize, message: String| Diagnostic {
+        position: start.map(|start| line_column(&toml, start + offset)),
+        ..Diagnostic::new(path, message).in_field(field)
+    };
+
+    let (mut events, unknown) = expand_wiki_links(&input, descriptions);
+    let mut diagnostics: Vec<_> = unknown.into_iter()
+        .map(|(name, offset)| diagnostic(offset, format!("there is no example named `{name}`")))
+        .collect();
+
+    // the links are checked in the field as it is written, to know where they are
+    for (event, range) in pulldown_cmark::Parser::new(&input).into_offset_iter() {
+        let Event::Start(Tag::Link(_, destination, _)) = event else {
+            continue
+        };
+        let target = match (destination.strip_prefix('#'), destination.strip_prefix("./")) {
+            (Some(target), _) | (_, Some(target)) => target,
+            (None, None) => continue,
+        };
+        // `#foo:L12` is a link to lines of `foo`
+        let name = target.split(':').next().unwrap_or_default();
+        if name != API_INDEX && !descriptions.contains_key(name) {
+            let offset = input[range.clone()].rfind(&*destination).map_or(range.start, |i| range.start + i);
+            diagnostics.push(diagnostic(
+                offset,
+                format!("the link `{destination}` is broken, there is no example named `{name}`")
+            ));
+        }
+    }
+
+    if events.iter().any(|x| matches!(x, Event::Start(Tag::Heading(_,_,_)))) {
+        diagnostics.push(
+            Diagnostic::new(path, "headings are not allowed in this field")
+                .in_field(field)
+        );
+    }
+
+    for event in &mut events {
+        let Event::Start(Tag::Link(_, destination, _)) = event else {
+            continue
+        };
+        let target = match (destination.strip_prefix('#'), destination.strip_prefix("./")) {
+            (Some(target), _) | (_, Some(target)) => target.to_string(),
+            (None, None) => continue,
+        };
+        // the website has a single page, `./foo` would not exist
+        *destination = format!("#{target}").into();
+    }
+
+    if !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+
+    let mut rendered = Vec::with_capacity(events.len());
+    let mut events = events.into_iter();
+    while let Some(event) = events.next() {
+        match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(language))) => {
+                let mut block = vec![Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(language.clone())))];
+                let mut code = String::new();
+                for event in events.by_ref() {
+                    if let Event::Text(text) = &event {
+                        code.push_str(text);
+                    }
+                    let end = matches!(event, Event::End(Tag::CodeBlock(_)));
+                    block.push(event);
+                    if end {
+                        break
+                    }
+                }
+                // the languages syntect does not know stay plain code
+                match markdown.highlighter.highlight_snippet(&code, &language) {
+                    Some(html) => rendered.push(Event::Html(html.into())),
+                    None => rendered.extend(block),
+                }
+            },
+            Event::Code(code) => match api_of_code(&code, markdown.api_usages) {
+                Some(api) => {
+                    let mut html = format!("<a class=\"api-link\" href=\"#{API_INDEX}:{api}\"><code>");
+                    pulldown_cmark::escape::escape_html(&mut html, &code).unwrap();
+                    html.push_str("</code></a>");
+                    rendered.push(Event::Html(html.into()));
+                },
+                None => rendered.push(Event::Code(code)),
+            },
+            event => rendered.push(event),
+        }
+    }
 
     // Write to a new String buffer.
     let mut html_output = String::new();
-    pulldown_cmark::html::push_html(&mut html_output, checked_parser);
-    html_output
+    pulldown_cmark::html::push_html(&mut html_output, rendered.into_iter());
+    Ok(html_output)
+}
+
+/// where the files of an example are:
+/// either `foo.rs`, `foo.toml` and `foo.css`,
+/// or a `foo/` directory with `mod.rs`, `foo.toml`, `style.css` and submodules
+struct ExampleFiles {
+    name: String,
+    /// the directory of a multi-file example
+    directory: Option<PathBuf>,
+    /// the rust files, the entrypoint first
+    sources: Vec<PathBuf>,
+    toml: PathBuf,
+    css: Option<PathBuf>,
+}
+
+impl ExampleFiles {
+    /// how a source file is named in the code viewer
+    fn display_path(&self, source: &Path) -> String {
+        let relative = match &self.directory {
+            Some(d) => source.strip_prefix(d).unwrap_or(source),
+            None => source.file_name().map(Path::new).unwrap_or(source),
+        };
+        relative.display().to_string()
+    }
+
+    /// where a source file goes in the exported project
+    fn project_path(&self, source: &Path) -> String {
+        match &self.directory {
+            Some(_) => format!("src/{}/{}", self.name, self.display_path(source)),
+            None => format!("src/{}", self.display_path(source)),
+        }
+    }
+}
+
+/// the files of a standalone trunk project that runs the example,
+/// except its sources that go in `src/`
+fn project_files(name: &str, info: &Info, cargo_setup: &str, css: &str) -> Vec<(String, String)> {
+    let mut files = vec![
+        ("Cargo.toml".to_string(), format!(
+r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+{cargo_setup}
+"#)),
+        ("src/main.rs".to_string(), format!(
+r#"mod {name};
+
+fn main() {{
+    leptos::mount_to_body({name}::showcase)
+}}
+"#)),
+        ("index.html".to_string(), format!(
+r#"<!DOCTYPE html>
+<html>
+    <head>
+        <title>{name}</title>
+        <link data-trunk rel="rust" data-wasm-opt="z">{}
+    </head>
+    <body></body>
+</html>
+"#, if css.is_empty() {""} else {"\n        <link data-trunk rel=\"css\" href=\"style.css\">"})),
+        ("README.md".to_string(), format!(
+r#"# {name}
+
+{}
+
+Exported from [leptos by example](https://rambip.github.io/leptos-by-example/#{name}).
+
+To run it, install [trunk](https://trunkrs.dev) and the `wasm32-unknown-unknown` target, then run `trunk serve --open`.
+"#, info.description.trim())),
+    ];
+
+    if !css.is_empty() {
+        files.push(("style.css".to_string(), css.to_string()));
+    }
+
+    // the `nightly` feature of leptos needs a nightly compiler
+    if info.features.iter().any(|f| f=="nightly") {
+        files.push(("rust-toolchain.toml".to_string(),
+r#"[toolchain]
+channel = "nightly"
+targets = ["wasm32-unknown-unknown"]
+"#.to_string()));
+    }
+    files
+}
+
+/// all the `.rs` files in a directory and its subdirectories
+fn rust_files(directory: &Path, result: &mut Vec<PathBuf>) -> Result<(), io::Error> {
+    for f in read_dir(directory)? {
+        let f = f?.path();
+        if f.is_dir() {
+            rust_files(&f, result)?;
+        }
+        else if f.extension().is_some_and(|e| e=="rs") {
+            result.push(f);
+        }
+    }
+    Ok(())
+}
+
+/// lists the files of the `example` directory and checks that they go together:
+/// each `foo.rs` needs a `foo.toml` and the other way around,
+/// a `foo.css` needs an example to style,
+/// and a `foo/` directory needs a `mod.rs` and a `foo.toml`.
+/// Returns the complete examples, sorted by name
+fn example_files(path: &Path, report: &mut Report) -> Result<Vec<ExampleFiles>, io::Error> {
+    let mut files: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut directories = Vec::new();
+    for f in read_dir(path)? {
+        let f = f?;
+        let meta = f.metadata()?;
+        let f = f.path();
+        if meta.is_dir() {
+            directories.push(f);
+            continue
+        }
+        let (Some(stem), Some(extension)) = (
+            f.file_stem().and_then(|x| x.to_str()),
+            f.extension().and_then(|x| x.to_str())
+        ) else {
+            continue
+        };
+        if f.file_name().is_some_and(|x| x==CATEGORIES_FILE || x==PATH_FILE) {
+            continue
+        }
+        files.entry(stem.to_string()).or_default().push(extension.to_string());
+    }
+
+    let mut examples = Vec::new();
+    for (name, extensions) in files {
+        let has = |e: &str| extensions.iter().any(|x| x==e);
+        let file = |e: &str| path.join(format!("{name}.{e}"));
+
+        if has("rs") && directories.contains(&path.join(&name)) {
+            report.push(Diagnostic::new(
+                file("rs").display().to_string(),
+                format!("`{}/` also defines the example `{name}`, keep only one of them", path.join(&name).display())
+            ));
+            directories.retain(|d| d != &path.join(&name));
+            continue
+        }
+
+        match (has("rs"), has("toml")) {
+            (true, true) => examples.push(ExampleFiles {
+                name: name.clone(),
+                directory: None,
+                sources: vec![file("rs")],
+                toml: file("toml"),
+                css: has("css").then(|| file("css")),
+            }),
+            (true, false) => report.push(Diagnostic::new(
+                file("rs").display().to_string(),
+                format!("no documentation for this example, please create `{}`", file("toml").display())
+            )),
+            (false, true) => report.push(Diagnostic::new(
+                file("toml").display().to_string(),
+                format!("no example for this documentation, `{}` is missing", file("rs").display())
+            )),
+            (false, false) => (),
+        }
+        if has("css") && !has("rs") {
+            report.push(Diagnostic::new(
+                file("css").display().to_string(),
+                format!("no example to style, `{}` is missing", file("rs").display())
+            ));
+        }
+    }
+
+    for directory in directories {
+        let Some(name) = directory.file_name().and_then(|x| x.to_str()).map(str::to_string) else {
+            continue
+        };
+        let entrypoint = directory.join("mod.rs");
+        let toml = directory.join(format!("{name}.toml"));
+
+        if !entrypoint.is_file() {
+            report.push(Diagnostic::new(
+                format!("{}/", directory.display()),
+                format!("a multi-file example needs a `mod.rs`, `{}` is missing", entrypoint.display())
+            ));
+        }
+        if !toml.is_file() {
+            report.push(Diagnostic::new(
+                format!("{}/", directory.display()),
+                format!("no documentation for this example, please create `{}`", toml.display())
+            ));
+        }
+        if !entrypoint.is_file() || !toml.is_file() {
+            continue
+        }
+
+        let mut sources = Vec::new();
+        rust_files(&directory, &mut sources)?;
+        sources.retain(|x| x != &entrypoint);
+        sources.sort();
+        sources.insert(0, entrypoint);
+
+        let css = directory.join("style.css");
+        examples.push(ExampleFiles {
+            name,
+            directory: Some(directory),
+            sources,
+            toml,
+            css: css.is_file().then_some(css),
+        });
+    }
+
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+/// checks that the example defines a `pub fn showcase`,
+/// so that the error is not a confusing one inside the generated code
+fn check_showcase(path: &Path, source: &str) -> Result<(), Diagnostic> {
+    let path = path.display().to_string();
+    let position = |span: Span| (span.start().line, span.start().column + 1);
+
+    let file = syn::parse_file(source)
+        .map_err(|e| Diagnostic {
+            position: Some(position(e.span())),
+            ..Diagnostic::new(&path, format!("cannot parse the example: {e}"))
+        })?;
+
+    let showcase = file.items.iter().find_map(|item| match item {
+        syn::Item::Fn(f) if f.sig.ident == "showcase" => Some(f),
+        _ => None,
+    });
+
+    match showcase {
+        None => Err(Diagnostic::new(&path, "the example must define a `pub fn showcase() -> impl IntoView`")),
+        Some(f) if !matches!(f.vis, syn::Visibility::Public(_)) => Err(Diagnostic {
+            position: Some(position(f.sig.ident.span())),
+            ..Diagnostic::new(&path, "the `showcase` function must be `pub`")
+        }),
+        Some(_) => Ok(()),
+    }
+}
+
+/// the environment variable that shows the draft examples
+const DRAFTS_VARIABLE: &str = "LBE_DRAFTS";
+
+fn show_drafts() -> bool {
+    println!("cargo:rerun-if-env-changed={DRAFTS_VARIABLE}");
+    std::env::var(DRAFTS_VARIABLE).is_ok_and(|v| v == "1")
+}
+
+/// removes the highlighted files and the pages of the examples that are not built anymore,
+/// so that trunk does not publish them
+fn prune_generated(names: &[String]) -> Result<(), io::Error> {
+    let built = |name: &std::ffi::OsStr| names.iter().any(|n| name == n.as_str());
+    if let Ok(entries) = read_dir(HIGHLIGHTED_DIR) {
+        for entry in entries {
+            let entry = entry?;
+            if !built(&entry.file_name()) {
+                fs::remove_dir_all(entry.path())?;
+            }
+        }
+    }
+    if let Ok(entries) = read_dir(PAGES_DIR) {
+        for entry in entries {
+            let path = entry?.path();
+            if !path.file_stem().is_some_and(|stem| built(stem) || stem == PAGES_INDEX) {
+                fs::remove_file(path)?;
+            }
+        }
+    }
+    Ok(())
 }
 
 /// reads the `example` directory.
-/// For each `foo.rs`, it will read it,
-/// preprocess for syntax-highlighting,
+/// For each example, it will read its rust files,
+/// preprocess them for syntax-highlighting,
 /// read and parse corresponding `foo.toml` metadata
-/// and eventually load `foo.css`
+/// and eventually load the css.
+/// Problems in the example files are added to the `report`
+/// instead of stopping the build at the first one.
+/// Only the examples whose cargo feature is enabled are built.
+/// Returns the names of the examples built, and of every example found,
+/// even the ones with problems or left out by the features
 fn read_examples(path: &Path, 
                  includes: &mut TokenStream, 
                  examples: &mut TokenStream, 
-                 n_examples: &mut usize) -> Result<(), io::Error>{
-    for f in read_dir(path)? {
-        let f = f?;
-        let meta = f.metadata()?;
-        if meta.is_file() && f.path().extension().unwrap()=="rs" {
-            let file_name = f.path()
-                .file_stem()
-                .unwrap()
-                .to_str()
-                .unwrap()
-                .to_string();
-
-            let raw_css = fs::read(format!("examples/{file_name}.css")).unwrap_or(Vec::new());
-            let css = String::from_utf8_lossy(&raw_css);
-
-            let info = match extract_toml_info(&file_name) {
-                Ok(x) => x,
-                Err(e) => {
-                    eprintln!("please provide all the required fields in {file_name}.toml");
-                    eprintln!("The missing field is {:?}", e.message());
-                    panic!()
-                }
-            };
+                 manifest: &toml::Table,
+                 categories: &Categories,
+                 highlighter: &Highlighter,
+                 api_usages: &mut BTreeMap<&'static str, Vec<String>>,
+                 search_tokens: &mut BTreeMap<String, BTreeSet<String>>,
+                 report: &mut Report) -> Result<(Vec<String>, Vec<String>), io::Error>{
+    let mut names = Vec::new();
+    let dependencies = manifest_table(manifest, "dependencies");
+    let features = manifest_table(manifest, "features");
+    let mut links = Vec::new();
+    let manifest_dir = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").expect("cargo sets CARGO_MANIFEST_DIR"));
 
-            let description = info.description;
+    let all_files = example_files(path, report)?;
+    let infos: Vec<_> = all_files.iter()
+        .map(|f| read_toml::<Info>(&f.toml))
+        .collect();
+    // the hidden drafts are left out, as if their files did not exist
+    let show_drafts = show_drafts();
+    let (all_files, infos): (Vec<_>, Vec<_>) = all_files.into_iter()
+        .zip(infos)
+        .filter(|(_, info)| show_drafts || !info.as_ref().is_ok_and(|i| i.draft))
+        .unzip();
+    // every example, even the ones with problems or left out by the features,
+    // so that a link to them is not reported as broken
+    let known: Vec<_> = all_files.iter().map(|f| f.name.clone()).collect();
+    // the descriptions of every example, for the links between them
+    let descriptions: BTreeMap<_, _> = all_files.iter()
+        .zip(&infos)
+        .map(|(f, info)| (
+            f.name.clone(),
+            info.as_ref().map(|i| i.description.trim().to_string()).unwrap_or_default()
+        ))
+        .collect();
 
-            let motivation = html_from_markdown(&file_name, info.motivation);
-            let related=quote_option(
-                info.related.map(|x| html_from_markdown(&file_name, x))
-            );
+    // the leptos items of every example, for the API index.
+    // The sources are read again below, and their errors reported there
+    for files in all_files.iter().filter(|f| example_enabled(&f.name)) {
+        let sources: Vec<_> = files.sources.iter()
+            .filter_map(|p| fs::read_to_string(p).ok())
+            .collect();
+        for api in used_apis(&sources) {
+            api_usages.entry(api).or_default().push(files.name.clone());
+        }
+    }
 
-            format!("examples/{file_name}.css");
-
-            let example_name = Ident::new(&file_name, Span::call_site());
-            let relative_path = format!("../examples/{file_name}.rs");
-
-            let source = 
-                std::str::from_utf8(
-                    &fs::read(f.path())?
-                ).unwrap().to_string();
-
-            let highlighted_source = highlight(&source);
-
-            examples.extend(
-                quote!{
-                    Example {
-                        name: #file_name,
-                        source: #source,
-                        highlighted_source: #highlighted_source,
-                        code: pack_example(#example_name::showcase),
-                        css: stylist::style!(#css).unwrap(),
-                        description: #description,
-                        motivation: #motivation,
-                        related: #related,
-                    },
-                }
-            );
+    let markdown = Markdown {
+        descriptions,
+        api_usages: &*api_usages,
+        highlighter,
+    };
 
-            includes.extend(
-                quote!{
+    for (files, info) in all_files.into_iter().zip(infos) {
+        let file_name = files.name.clone();
+        let toml_path = files.toml.display().to_string();
+
+        let raw_css = match &files.css {
+            Some(css) => {
+                watch(css);
+                fs::read(css)?
+            },
+            None => Vec::new(),
+        };
+        let css = String::from_utf8_lossy(&raw_css);
+
+        let mut sources = Vec::new();
+        for source_path in &files.sources {
+            watch(source_path);
+            match String::from_utf8(fs::read(source_path)?) {
+                Ok(x) => sources.push(x),
+                Err(e) => report.push(Diagnostic::new(
+                    source_path.display().to_string(),
+                    format!("the source is not valid utf-8: {e}")
+                )),
+            }
+        }
+
+        // keep checking the other examples,
+        // the report will stop the build at the end
+        let info = match info {
+            Ok(x) => x,
+            Err(d) => {
+                report.push(d);
+                continue
+            }
+        };
+        if sources.len() != files.sources.len() {
+            continue
+        }
+
+        // every check runs, so that all the problems of an example are reported in one pass.
+        // An example with a problem is only left out of the generated code
+        let mut problems = check_feature(&file_name, &info, &dependencies, &features);
+        problems.extend(check_showcase(&files.sources[0], &sources[0]).err());
+        problems.extend(files.sources.iter()
+            .zip(&sources)
+            .filter_map(|(p, s)| check_markers(p, s).err())
+        );
+        problems.extend(files.sources.iter()
+            .zip(&sources)
+            .flat_map(|(p, s)| check_signal_calls(p, s, &info.features))
+        );
+        problems.extend(check_classification(&toml_path, &info, categories));
+        let cargo_setup = match cargo_setup(&toml_path, &info, &dependencies) {
+            Ok(setup) => Some(setup),
+            Err(d) => {
+                problems.extend(d);
+                None
+            }
+        };
+        let motivation = match html_from_markdown(&toml_path, "motivation", info.motivation.clone(), &markdown) {
+            Ok(html) => Some(html),
+            Err(d) => {
+                problems.extend(d);
+                None
+            }
+        };
+        let related = match info.related.clone().map(|x| html_from_markdown(&toml_path, "related", x, &markdown)) {
+            None => Some(None),
+            Some(Ok(html)) => Some(Some(html)),
+            Some(Err(d)) => {
+                problems.extend(d);
+                None
+            }
+        };
+        links.extend(info.links.iter().map(|l| (toml_path.clone(), l.clone())));
+
+        let failed = !problems.is_empty();
+        problems.into_iter().for_each(|d| report.push(d));
+        if failed || !example_enabled(&file_name) {
+            continue
+        }
+        let (cargo_setup, motivation, related) = match (cargo_setup, motivation, related) {
+            (Some(cargo_setup), Some(motivation), Some(related)) => (cargo_setup, motivation, related),
+            _ => continue,
+        };
+
+        names.push(file_name.clone());
+        let apis = used_apis(&sources);
+        let (project_file_paths, project_file_contents): (Vec<_>, Vec<_>) =
+            project_files(&file_name, &info, &cargo_setup, &css)
+            .into_iter()
+            .unzip();
+
+        let features = &info.features;
+        let example_dependencies = &info.dependencies;
+        let example_links = &info.links;
+        let category = quote_option(info.category.clone());
+        let tags = &info.tags;
+        let difficulty = match info.difficulty {
+            Some(d) => {
+                let d = d.quote();
+                quote!{Some(#d)}
+            },
+            None => quote!{None},
+        };
+
+        // the text of the toml file and the code, comments included, for the search index
+        // everything a quoted phrase of the search bar is looked for in
+        let toml_text = [&file_name, &info.description, &info.motivation]
+            .into_iter()
+            .chain(&info.related)
+            .chain(&info.tags)
+            .chain(&info.category);
+        search_tokens.insert(
+            file_name.clone(),
+            toml_text.chain(&sources)
+                .flat_map(|text| search_index::tokens(text))
+                .collect()
+        );
+
+        let description = info.description;
+        let draft = info.draft;
+
+        let related_option = quote_option(related.clone());
+        let example_name = Ident::new(&file_name, Span::call_site());
+        // the generated code lives in OUT_DIR, so its paths must be absolute
+        let absolute_path = manifest_dir.join(&files.sources[0]).display().to_string();
+
+        let file_paths: Vec<_> = files.sources.iter().map(|p| files.display_path(p)).collect();
+        let project_paths = files.sources.iter().map(|p| files.project_path(p));
+        let highlighted: Vec<_> = sources.iter().map(|s| highlighter.highlight(s)).collect();
+        let highlighted_urls = file_paths.iter()
+            .zip(&highlighted)
+            .map(|(p, html)| write_highlighted(&file_name, p, html))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        write_page(Page {
+            name: file_name.clone(),
+            description: description.clone(),
+            motivation: motivation.clone(),
+            related: related.clone(),
+            links: info.links.clone(),
+            files: file_paths.iter().cloned().zip(highlighted).collect(),
+        })?;
+
+        examples.extend(
+            quote!{
+                Example {
+                    name: #file_name,
+                    files: &[
+                        #(SourceFile {
+                            path: #file_paths,
+                            project_path: #project_paths,
+                            source: #sources,
+                            highlighted_url: #highlighted_urls,
+                        }),*
+                    ],
+                    code: pack_example(#example_name::showcase),
+                    css: stylist::style!(#css).unwrap(),
+                    description: #description,
+                    motivation: #motivation,
+                    related: #related_option,
+                    features: &[#(#features),*],
+                    dependencies: &[#(#example_dependencies),*],
+                    links: &[#(#example_links),*],
+                    cargo_setup: #cargo_setup,
+                    project: &[
+                        #(ProjectFile {
+                            path: #project_file_paths,
+                            contents: #project_file_contents,
+                        }),*
+                    ],
+                    category: #category,
+                    tags: &[#(#tags),*],
+                    difficulty: #difficulty,
+                    apis: &[#(#apis),*],
+                    draft: #draft,
+                },
+            }
+        );
+
+        // a multi-file example is a real module,
+        // so that its `mod.rs` can declare submodules
+        includes.extend(
+            match files.directory {
+                None => quote!{
                     mod #example_name {
-                        include!(#relative_path);
+                        include!(#absolute_path);
                     }
-                }
-            );
+                },
+                Some(_) => quote!{
+                    #[path = #absolute_path]
+                    mod #example_name;
+                },
+            }
+        );
+    };
 
-            *n_examples += 1;
+    for (toml_path, link) in links {
+        if !known.contains(&link) {
+            report.push(
+                Diagnostic::new(toml_path, format!("there is no example named `{link}`"))
+                    .in_field("links")
+            );
         }
-    };
-    Ok(())
+    }
+    Ok((names, known))
 }
 
 fn main() -> Result<(), io::Error> {
@@ -149,22 +1608,108 @@ fn main() -> Result<(), io::Error> {
     let mut includes = TokenStream::new();
     let mut examples = TokenStream::new();
 
-    let mut n_examples = 0usize;
+    let mut report = Report::default();
+
+    let mut api_usages = BTreeMap::new();
+    let mut search_tokens = BTreeMap::new();
+
+    // new and removed files
+    watch(Path::new("examples"));
+    watch(Path::new(SYNTAXES_DIR));
+    // the outputs trunk copies, so that deleting them writes them again.
+    // Writing them reruns the script once more, which writes nothing new
+    watch(Path::new(HIGHLIGHTED_DIR));
+    watch(Path::new(PAGES_DIR));
+
+    let manifest = read_manifest()?;
+
+    let highlighter = Highlighter::new(DocsLinks::read());
 
-    read_examples(Path::new("./examples"),
+    let categories: Categories = read_optional_toml(
+        &Path::new("examples").join(CATEGORIES_FILE),
+        &mut report
+    );
+    let learning_path: LearningPath = read_optional_toml(
+        &Path::new("examples").join(PATH_FILE),
+        &mut report
+    );
+
+    let (names, known) = read_examples(Path::new("examples"),
                   &mut includes,
                   &mut examples,
-                  &mut n_examples)?;
+                  &manifest,
+                  &categories,
+                  &highlighter,
+                  &mut api_usages,
+                  &mut search_tokens,
+                  &mut report)?;
 
+    prune_generated(&names)?;
+    write_pages_index(&names)?;
+
+    check_learning_path(&learning_path, &known)
+        .into_iter()
+        .for_each(|d| report.push(d));
+
+    if !report.is_empty() {
+        report.print();
+        std::process::exit(1);
+    }
 
-    let generated_rust = quote!{
-        //! generated automatically by build.rs
 
+    let category_names = categories.categories.iter().map(|c| &c.name);
+    let category_titles = categories.categories.iter().map(|c| &c.title);
+    let all_tags = &categories.tags;
+    // a smaller gallery has a shorter learning path
+    let lessons = learning_path.lessons.iter().filter(|l| names.contains(l));
+    let apis = APIS.iter().filter_map(|(name, krate, kind)| {
+        let examples = api_usages.get(name)?;
+        let kind = kind.quote();
+        Some(quote!{
+            Api {
+                name: #name,
+                krate: #krate,
+                kind: #kind,
+                examples: &[#(#examples),*],
+            }
+        })
+    });
+
+    let search_tables = search_index::build(&search_tokens);
+    let search_names = &search_tables.names;
+    fn quote_table<T: quote::ToTokens>(table: &[(String, Vec<T>)]) -> TokenStream {
+        let rows = table.iter().map(|(key, ids)| quote!{(#key, &[#(#ids),*])});
+        quote!{&[#(#rows),*]}
+    }
+    let search_tokens = quote_table(&search_tables.tokens);
+    let search_trigrams = quote_table(&search_tables.trigrams);
+
+    let generated_rust = quote!{
         #includes
 
-        use super::{Example, pack_example};
+        use super::{Example, SourceFile, ProjectFile, Category, Difficulty, Api, ApiKind, SearchIndex, pack_example};
 
-        pub const N_EXAMPLES: usize = #n_examples;
+        pub const CATEGORIES: &[Category] = &[
+            #(Category {
+                name: #category_names,
+                title: #category_titles,
+            }),*
+        ];
+
+        pub const TAGS: &[&str] = &[#(#all_tags),*];
+
+        /// the names of the examples, in the order a beginner should read them
+        pub const LEARNING_PATH: &[&str] = &[#(#lessons),*];
+
+        /// the leptos items used by the examples, for the API index
+        pub const APIS: &[Api] = &[#(#apis),*];
+
+        /// the words of the examples, so that the search only reads the sources that may match
+        pub static SEARCH_INDEX: SearchIndex<'static> = SearchIndex {
+            names: &[#(#search_names),*],
+            tokens: #search_tokens,
+            trigrams: #search_trigrams,
+        };
         pub type Examples = std::collections::HashMap<&'static str, std::rc::Rc<Example>>;
 
         pub fn examples() -> Examples {
@@ -179,8 +1724,11 @@ fn main() -> Result<(), io::Error> {
 
     let pretty = prettyplease::unparse(&syn::parse2(generated_rust).unwrap());
 
-    File::create("src/examples.rs")?
-        .write_all(pretty.as_bytes())?;
+    let out_dir = PathBuf::from(std::env::var_os("OUT_DIR").expect("cargo sets OUT_DIR"));
+    File::create(out_dir.join("examples.rs"))?
+        .write_all(format!("// generated automatically by build.rs\n\n{pretty}").as_bytes())?;
+
+    write_theme_stylesheets()?;
 
     Ok(())
 
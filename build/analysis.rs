@@ -0,0 +1,724 @@
+//! the checks and scans of build.rs that do not touch the files:
+//! the markers and notes of the sources, the leptos items they use, the signals they call,
+//! and the links of the markdown fields.
+//! The app includes this file too, only for its unit tests: cargo does not run the tests of a build script
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use proc_macro2::{TokenStream, TokenTree, Ident};
+use syn::visit::Visit;
+
+/// a comment line that changes how the source is displayed
+#[derive(Clone, Copy, PartialEq)]
+pub enum Marker {
+    /// `// lbe:hide-start`, the following lines are folded
+    HideStart,
+    /// `// lbe:hide-end`
+    HideEnd,
+    /// `// lbe:focus`, the viewer starts at the following line
+    Focus,
+}
+
+impl Marker {
+    pub fn of_line(line: &str) -> Option<Self> {
+        match line.trim() {
+            "// lbe:hide-start" => Some(Marker::HideStart),
+            "// lbe:hide-end" => Some(Marker::HideEnd),
+            "// lbe:focus" => Some(Marker::Focus),
+            _ => None,
+        }
+    }
+}
+
+/// a `// NOTE:` comment, and the `//` comment lines that follow it.
+/// The viewer can show it as a side note instead of a comment
+pub struct Note {
+    /// the indices of the comment lines, starting at 0
+    pub comment: std::ops::Range<usize>,
+    /// the index of the line it is next to
+    pub line: usize,
+    pub markdown: String,
+}
+
+/// the notes of a source file
+pub fn notes(code: &str) -> Vec<Note> {
+    let lines: Vec<_> = code.lines().collect();
+    let is_comment = |line: &str| {
+        let line = line.trim_start();
+        line.starts_with("//")
+            && !line.starts_with("///")
+            && !line.starts_with("//!")
+            && !line.starts_with("// NOTE:")
+            && Marker::of_line(line).is_none()
+    };
+
+    let mut notes = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let Some(first) = lines[i].trim_start().strip_prefix("// NOTE:") else {
+            i += 1;
+            continue
+        };
+        let start = i;
+        let mut text = vec![first.trim_start()];
+        i += 1;
+        while i < lines.len() && is_comment(lines[i]) {
+            let rest = lines[i].trim_start().trim_start_matches("//");
+            text.push(rest.strip_prefix(' ').unwrap_or(rest));
+            i += 1;
+        }
+        // markers are not displayed, so the note goes next to the line after them
+        let line = (i..lines.len())
+            .find(|j| Marker::of_line(lines[*j]).is_none())
+            .unwrap_or(i - 1);
+        notes.push(Note {
+            comment: start..i,
+            line,
+            markdown: text.join("\n"),
+        });
+    }
+    notes
+}
+
+/// a problem found in one of the example files.
+/// They are all collected so that the contributor can fix
+/// every mistake in one pass
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub file: String,
+    pub field: Option<String>,
+    /// line and column, starting at 1
+    pub position: Option<(usize, usize)>,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn new(file: impl Into<String>, message: impl Into<String>) -> Self {
+        Diagnostic {
+            file: file.into(),
+            field: None,
+            position: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn in_field(mut self, field: impl Into<String>) -> Self {
+        self.field = Some(field.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ApiKind {
+    Function,
+    Struct,
+    Enum,
+    TypeAlias,
+    Trait,
+    Macro,
+    Attribute,
+    Component,
+}
+
+impl ApiKind {
+    /// as shown in the tooltip of the docs links
+    pub fn name(self) -> &'static str {
+        match self {
+            ApiKind::Function => "function",
+            ApiKind::Struct => "struct",
+            ApiKind::Enum => "enum",
+            ApiKind::TypeAlias => "type alias",
+            ApiKind::Trait => "trait",
+            ApiKind::Macro => "macro",
+            ApiKind::Attribute => "attribute macro",
+            ApiKind::Component => "component",
+        }
+    }
+
+    /// rustdoc names the page of an item `{prefix}.{name}.html`
+    pub fn rustdoc_prefix(self) -> &'static str {
+        match self {
+            ApiKind::Function | ApiKind::Component => "fn",
+            ApiKind::Struct => "struct",
+            ApiKind::Enum => "enum",
+            ApiKind::TypeAlias => "type",
+            ApiKind::Trait => "trait",
+            ApiKind::Macro => "macro",
+            ApiKind::Attribute => "attr",
+        }
+    }
+}
+
+/// the leptos items the API index knows about,
+/// with the crate that documents them.
+/// Add the new ones here when an example starts using them
+pub const APIS: &[(&str, &str, ApiKind)] = &[
+    ("view", "leptos", ApiKind::Macro),
+    ("component", "leptos", ApiKind::Attribute),
+    ("IntoView", "leptos", ApiKind::Trait),
+    ("CollectView", "leptos", ApiKind::Trait),
+    ("create_signal", "leptos", ApiKind::Function),
+    ("create_rw_signal", "leptos", ApiKind::Function),
+    ("create_memo", "leptos", ApiKind::Function),
+    ("create_effect", "leptos", ApiKind::Function),
+    ("create_resource", "leptos", ApiKind::Function),
+    ("create_local_resource", "leptos", ApiKind::Function),
+    ("create_action", "leptos", ApiKind::Function),
+    ("create_node_ref", "leptos", ApiKind::Function),
+    ("store_value", "leptos", ApiKind::Function),
+    ("provide_context", "leptos", ApiKind::Function),
+    ("use_context", "leptos", ApiKind::Function),
+    ("expect_context", "leptos", ApiKind::Function),
+    ("on_cleanup", "leptos", ApiKind::Function),
+    ("spawn_local", "leptos", ApiKind::Function),
+    ("set_timeout", "leptos", ApiKind::Function),
+    ("set_interval", "leptos", ApiKind::Function),
+    ("set_interval_with_handle", "leptos", ApiKind::Function),
+    ("request_animation_frame", "leptos", ApiKind::Function),
+    ("window_event_listener", "leptos", ApiKind::Function),
+    ("event_target", "leptos", ApiKind::Function),
+    ("event_target_value", "leptos", ApiKind::Function),
+    ("event_target_checked", "leptos", ApiKind::Function),
+    ("mount_to_body", "leptos", ApiKind::Function),
+    ("Signal", "leptos", ApiKind::Struct),
+    ("ReadSignal", "leptos", ApiKind::Struct),
+    ("WriteSignal", "leptos", ApiKind::Struct),
+    ("RwSignal", "leptos", ApiKind::Struct),
+    ("MaybeSignal", "leptos", ApiKind::Enum),
+    ("Memo", "leptos", ApiKind::Struct),
+    ("Resource", "leptos", ApiKind::Struct),
+    ("NodeRef", "leptos", ApiKind::Struct),
+    ("Callback", "leptos", ApiKind::Struct),
+    ("Children", "leptos", ApiKind::TypeAlias),
+    ("ChildrenFn", "leptos", ApiKind::TypeAlias),
+    ("View", "leptos", ApiKind::Enum),
+    ("Fragment", "leptos", ApiKind::Struct),
+    ("Errors", "leptos", ApiKind::Struct),
+    ("IntervalHandle", "leptos", ApiKind::Struct),
+    ("For", "leptos", ApiKind::Component),
+    ("Show", "leptos", ApiKind::Component),
+    ("Suspense", "leptos", ApiKind::Component),
+    ("Transition", "leptos", ApiKind::Component),
+    ("ErrorBoundary", "leptos", ApiKind::Component),
+    ("Portal", "leptos", ApiKind::Component),
+    ("Router", "leptos_router", ApiKind::Component),
+    ("Routes", "leptos_router", ApiKind::Component),
+    ("Route", "leptos_router", ApiKind::Component),
+    ("A", "leptos_router", ApiKind::Component),
+    ("Outlet", "leptos_router", ApiKind::Component),
+    ("create_query_signal", "leptos_router", ApiKind::Function),
+    ("use_navigate", "leptos_router", ApiKind::Function),
+    ("use_location", "leptos_router", ApiKind::Function),
+    ("use_params_map", "leptos_router", ApiKind::Function),
+    ("use_query_map", "leptos_router", ApiKind::Function),
+];
+
+/// the identifiers of a source file, and what they refer to,
+/// to find the leptos items it uses.
+/// `view!` is not parsed, so its tokens are scanned:
+/// `<Foo` is the component `Foo`
+#[derive(Default)]
+struct ApiVisitor {
+    paths: HashSet<String>,
+    components: HashSet<String>,
+    /// the `DOCUMENTED_CRATES` whose items are imported with a `*`
+    globs: HashSet<&'static str>,
+    /// the names imported from the `DOCUMENTED_CRATES`
+    imported: BTreeMap<String, &'static str>,
+    /// the names written with their crate, like `leptos::ev::click`
+    qualified: BTreeMap<String, &'static str>,
+    /// the names bound by a pattern, defined by an item of the file,
+    /// or imported from another crate: they are not the leptos items
+    locals: HashSet<String>,
+}
+
+/// the documented crate named `ident`
+fn documented_crate(ident: &impl std::fmt::Display) -> Option<&'static str> {
+    let ident = ident.to_string();
+    DOCUMENTED_CRATES.into_iter().find(|k| *k == ident)
+}
+
+impl ApiVisitor {
+    fn visit_tokens(&mut self, tokens: TokenStream) {
+        let tokens: Vec<_> = tokens.into_iter().collect();
+        let mut after_angle = false;
+        for (i, token) in tokens.iter().enumerate() {
+            match token {
+                TokenTree::Group(g) => self.visit_tokens(g.stream()),
+                TokenTree::Ident(ident) if after_angle => { self.components.insert(ident.to_string()); },
+                TokenTree::Ident(ident) => {
+                    self.paths.insert(ident.to_string());
+                    if let Some(krate) = documented_crate(ident) {
+                        // the last identifier of `krate::a::b`
+                        let last = tokens[i + 1..]
+                            .chunks(3)
+                            .map_while(|t| match t {
+                                [TokenTree::Punct(a), TokenTree::Punct(b), TokenTree::Ident(name)]
+                                    if a.as_char() == ':' && b.as_char() == ':' => Some(name),
+                                _ => None,
+                            })
+                            .last();
+                        if let Some(name) = last {
+                            self.qualified.insert(name.to_string(), krate);
+                        }
+                    }
+                },
+                TokenTree::Punct(_) | TokenTree::Literal(_) => (),
+            }
+            after_angle = matches!(token, TokenTree::Punct(p) if p.as_char() == '<');
+        }
+    }
+
+    /// adds the names imported by a `use` of `krate`,
+    /// or of another crate if there is none
+    fn visit_use_tree(&mut self, tree: &syn::UseTree, krate: Option<&'static str>) {
+        match tree {
+            syn::UseTree::Path(p) => self.visit_use_tree(&p.tree, krate),
+            syn::UseTree::Name(n) => match krate {
+                Some(krate) => { self.imported.entry(n.ident.to_string()).or_insert(krate); },
+                None => { self.locals.insert(n.ident.to_string()); },
+            },
+            syn::UseTree::Rename(r) => { self.locals.insert(r.rename.to_string()); },
+            syn::UseTree::Glob(_) => { self.globs.extend(krate); },
+            syn::UseTree::Group(g) => g.items.iter().for_each(|t| self.visit_use_tree(t, krate)),
+        }
+    }
+
+    /// the crate of the item that `name` refers to, if it is a documented one
+    fn krate_of(&self, name: &str) -> Option<&'static str> {
+        if let Some(krate) = self.qualified.get(name) {
+            return Some(krate)
+        }
+        if self.locals.contains(name) {
+            return None
+        }
+        self.imported.get(name).copied().or_else(|| {
+            APIS.iter()
+                .find(|(api, krate, _)| *api == name && self.globs.contains(krate))
+                .map(|(_, krate, _)| *krate)
+        })
+    }
+}
+
+impl<'ast> Visit<'ast> for ApiVisitor {
+    fn visit_path(&mut self, path: &'ast syn::Path) {
+        if let Some(last) = path.segments.last() {
+            self.paths.insert(last.ident.to_string());
+            if let (Some(krate), true) = (documented_crate(&path.segments[0].ident), path.segments.len() > 1) {
+                self.qualified.insert(last.ident.to_string(), krate);
+            }
+        }
+        syn::visit::visit_path(self, path);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        self.visit_tokens(mac.tokens.clone());
+        syn::visit::visit_macro(self, mac);
+    }
+
+    fn visit_item_use(&mut self, item: &'ast syn::ItemUse) {
+        match &item.tree {
+            syn::UseTree::Path(p) => self.visit_use_tree(&p.tree, documented_crate(&p.ident)),
+            tree => self.visit_use_tree(tree, None),
+        }
+    }
+
+    /// `let` patterns, the arguments of functions and closures, `match` arms
+    fn visit_pat_ident(&mut self, pat: &'ast syn::PatIdent) {
+        self.locals.insert(pat.ident.to_string());
+        syn::visit::visit_pat_ident(self, pat);
+    }
+
+    fn visit_item(&mut self, item: &'ast syn::Item) {
+        let ident = match item {
+            syn::Item::Fn(f) => Some(&f.sig.ident),
+            syn::Item::Struct(s) => Some(&s.ident),
+            syn::Item::Enum(e) => Some(&e.ident),
+            syn::Item::Type(t) => Some(&t.ident),
+            syn::Item::Trait(t) => Some(&t.ident),
+            syn::Item::Const(c) => Some(&c.ident),
+            syn::Item::Static(s) => Some(&s.ident),
+            syn::Item::Mod(m) => Some(&m.ident),
+            _ => None,
+        };
+        self.locals.extend(ident.map(Ident::to_string));
+        syn::visit::visit_item(self, item);
+    }
+}
+
+/// the names of the `APIS` used by the sources of an example:
+/// the ones imported from their crate, with a `*` or by name, or written with their crate
+pub fn used_apis(sources: &[String]) -> Vec<&'static str> {
+    let visitors: Vec<_> = sources.iter()
+        .filter_map(|s| syn::parse_file(s).ok())
+        .map(|file| {
+            let mut visitor = ApiVisitor::default();
+            visitor.visit_file(&file);
+            visitor
+        })
+        .collect();
+    APIS.iter()
+        .filter(|(name, krate, kind)| visitors.iter().any(|visitor| {
+            let used = match kind {
+                ApiKind::Component => visitor.components.contains(*name),
+                _ => visitor.paths.contains(*name),
+            };
+            used && visitor.krate_of(name) == Some(*krate)
+        }))
+        .map(|(name, _, _)| *name)
+        .collect()
+}
+
+/// the crates whose items link to docs.rs
+pub const DOCUMENTED_CRATES: [&str; 3] = ["leptos", "leptos_router", "leptos_use"];
+
+/// links the items of the leptos crates to their page on docs.rs,
+/// at the version the website is built with
+/// the names of a source file that link to the docs, with their crate and kind:
+/// the ones that refer to an item of the `DOCUMENTED_CRATES`, see `ApiVisitor`
+pub fn documented_items(file: Option<&syn::File>) -> BTreeMap<String, (&'static str, Option<ApiKind>)> {
+    let Some(file) = file else {
+        return BTreeMap::new()
+    };
+    let mut visitor = ApiVisitor::default();
+    visitor.visit_file(file);
+    visitor.paths.iter()
+        .chain(&visitor.components)
+        // on the lines of the `use`
+        .chain(visitor.imported.keys())
+        .filter_map(|name| {
+            let krate = visitor.krate_of(name)?;
+            let kind = APIS.iter()
+                .find(|(api, k, _)| api == name && *k == krate)
+                .map(|(_, _, kind)| *kind);
+            Some((name.clone(), (krate, kind)))
+        })
+        .collect()
+}
+
+/// the events of `markdown`, where `[[foo]]` is a link to the example `foo`,
+/// titled with its description.
+/// The code spans and blocks are kept as they are written.
+/// Returns the unknown examples too, with the offset of their link in `markdown`
+pub fn expand_wiki_links<'a>(markdown: &'a str, descriptions: &BTreeMap<String, String>)
+    -> (Vec<pulldown_cmark::Event<'a>>, Vec<(String, usize)>) {
+    use pulldown_cmark::{Event, LinkType, Tag};
+
+    let mut events = Vec::new();
+    let mut unknown = Vec::new();
+    // the parser splits the text on the brackets: the consecutive texts are joined,
+    // with the offset where they start
+    let mut text: Option<(String, usize)> = None;
+    let mut in_code_block = false;
+    let mut expand = |text: Option<(String, usize)>, events: &mut Vec<Event<'a>>| {
+        let Some((text, offset)) = text else {
+            return
+        };
+        let mut rest = text.as_str();
+        while let Some(start) = rest.find("[[") {
+            let Some(len) = rest[start..].find("]]") else {
+                break
+            };
+            let name = &rest[start+2..start+len];
+            match descriptions.get(name) {
+                Some(description) => {
+                    let title = description.split_whitespace().collect::<Vec<_>>().join(" ");
+                    events.push(Event::Text(rest[..start].to_string().into()));
+                    events.push(Event::Start(Tag::Link(LinkType::Inline, format!("#{name}").into(), title.clone().into())));
+                    events.push(Event::Text(name.to_string().into()));
+                    events.push(Event::End(Tag::Link(LinkType::Inline, format!("#{name}").into(), title.into())));
+                },
+                None => {
+                    unknown.push((name.to_string(), offset + text.len() - rest.len() + start));
+                    events.push(Event::Text(rest[..start+len+2].to_string().into()));
+                },
+            }
+            rest = &rest[start+len+2..];
+        }
+        if !rest.is_empty() {
+            events.push(Event::Text(rest.to_string().into()));
+        }
+    };
+
+    for (event, range) in pulldown_cmark::Parser::new(markdown).into_offset_iter() {
+        match &event {
+            Event::Text(t) if !in_code_block => {
+                match &mut text {
+                    Some((joined, _)) => joined.push_str(t),
+                    None => text = Some((t.to_string(), range.start)),
+                }
+                continue
+            },
+            Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+            Event::End(Tag::CodeBlock(_)) => in_code_block = false,
+            _ => (),
+        }
+        expand(text.take(), &mut events);
+        events.push(event);
+    }
+    expand(text.take(), &mut events);
+    (events, unknown)
+}
+
+/// checks that the `lbe:hide-start` and `lbe:hide-end` markers
+/// come in pairs, and are not nested
+pub fn check_markers(path: &Path, source: &str) -> Result<(), Diagnostic> {
+    let diagnostic = |line: usize, message: &str| Diagnostic {
+        position: Some((line, 1)),
+        ..Diagnostic::new(path.display().to_string(), message)
+    };
+    let mut hidden_since = None;
+    for (i, line) in source.lines().enumerate() {
+        match (Marker::of_line(line), hidden_since) {
+            (Some(Marker::HideStart), Some(start)) =>
+                return Err(diagnostic(i + 1, &format!("hidden regions cannot be nested, the one at line {start} is not closed"))),
+            (Some(Marker::HideStart), None) => hidden_since = Some(i + 1),
+            (Some(Marker::HideEnd), None) =>
+                return Err(diagnostic(i + 1, "`lbe:hide-end` without a `lbe:hide-start`")),
+            (Some(Marker::HideEnd), Some(_)) => hidden_since = None,
+            _ => (),
+        }
+    }
+    match hidden_since {
+        Some(start) => Err(diagnostic(start, "`lbe:hide-start` without a `lbe:hide-end`")),
+        None => Ok(()),
+    }
+}
+
+/// the functions that create signals,
+/// whose results can only be called like functions with the `nightly` feature
+const SIGNAL_CONSTRUCTORS: [&str; 10] = [
+    "create_signal", "create_rw_signal", "create_memo", "create_slice", "create_query_signal",
+    "RwSignal::new", "Memo::new", "Signal::derive", "MaybeSignal::derive", "SignalSetter::map",
+];
+
+/// the signals bound by `let`, and the places where a name is called like a function
+#[derive(Default)]
+struct SignalCalls {
+    signals: HashSet<String>,
+    calls: Vec<Ident>,
+}
+
+impl SignalCalls {
+    fn bind(&mut self, pat: &syn::Pat) {
+        match pat {
+            syn::Pat::Ident(p) => { self.signals.insert(p.ident.to_string()); },
+            syn::Pat::Tuple(t) => t.elems.iter().for_each(|p| self.bind(p)),
+            syn::Pat::Type(t) => self.bind(&t.pat),
+            _ => (),
+        }
+    }
+
+    /// `name(..)` in the tokens of a macro, like `view!`, but not `.name(..)` or `fn name(..)`
+    fn visit_tokens(&mut self, tokens: TokenStream) {
+        let tokens: Vec<_> = tokens.into_iter().collect();
+        for (i, token) in tokens.iter().enumerate() {
+            match (token, tokens.get(i + 1)) {
+                (TokenTree::Group(g), _) => self.visit_tokens(g.stream()),
+                (TokenTree::Ident(name), Some(TokenTree::Group(g))) if g.delimiter() == proc_macro2::Delimiter::Parenthesis => {
+                    let method = match i.checked_sub(1).map(|previous| &tokens[previous]) {
+                        Some(TokenTree::Punct(p)) => p.as_char() == '.',
+                        Some(TokenTree::Ident(ident)) => ident == "fn",
+                        _ => false,
+                    };
+                    if !method {
+                        self.calls.push(name.clone());
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for SignalCalls {
+    fn visit_local(&mut self, local: &'ast syn::Local) {
+        let constructor = match local.init.as_ref().map(|i| &*i.expr) {
+            Some(syn::Expr::Call(call)) => match &*call.func {
+                syn::Expr::Path(p) => {
+                    let segments: Vec<_> = p.path.segments.iter().map(|s| s.ident.to_string()).collect();
+                    let last = |n: usize| segments[segments.len().saturating_sub(n)..].join("::");
+                    SIGNAL_CONSTRUCTORS.contains(&last(1).as_str()) || SIGNAL_CONSTRUCTORS.contains(&last(2).as_str())
+                },
+                _ => false,
+            },
+            _ => false,
+        };
+        if constructor {
+            self.bind(&local.pat);
+        }
+        syn::visit::visit_local(self, local);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+        if let syn::Expr::Path(p) = &*call.func {
+            if let Some(name) = p.path.get_ident() {
+                self.calls.push(name.clone());
+            }
+        }
+        syn::visit::visit_expr_call(self, call);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        self.visit_tokens(mac.tokens.clone());
+        syn::visit::visit_macro(self, mac);
+    }
+}
+
+/// checks that an example calls its signals like functions only if it
+/// declares the `nightly` feature in its `features`, which the stable toolchain does not have
+pub fn check_signal_calls(path: &Path, source: &str, features: &[String]) -> Vec<Diagnostic> {
+    if features.iter().any(|f| f == "nightly") {
+        return Vec::new()
+    }
+    let Ok(file) = syn::parse_file(source) else {
+        // reported by `check_showcase`
+        return Vec::new()
+    };
+    let mut visitor = SignalCalls::default();
+    visitor.visit_file(&file);
+    visitor.calls.iter()
+        .filter(|name| visitor.signals.contains(&name.to_string()))
+        .map(|name| Diagnostic {
+            position: Some((name.span().start().line, name.span().start().column + 1)),
+            ..Diagnostic::new(path.display().to_string(), format!(
+                "calling the signal `{name}` like a function needs the `nightly` feature: \
+                 add `features = [\"nightly\"]`, or use `.get()` and `.set(..)`"
+            ))
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn descriptions() -> BTreeMap<String, String> {
+        BTreeMap::from([("counter".to_string(), "A simpler\ncounter component.".to_string())])
+    }
+
+    /// the html of `markdown`, and the unknown examples it links to
+    fn wiki_html(markdown: &str) -> (String, Vec<(String, usize)>) {
+        let (events, unknown) = expand_wiki_links(markdown, &descriptions());
+        let mut html = String::new();
+        pulldown_cmark::html::push_html(&mut html, events.into_iter());
+        (html, unknown)
+    }
+
+    #[test]
+    fn wiki_links_are_titled_with_the_description() {
+        let (html, unknown) = wiki_html("See [[counter]].");
+        assert_eq!(html, "<p>See <a href=\"#counter\" title=\"A simpler counter component.\">counter</a>.</p>\n");
+        assert!(unknown.is_empty());
+    }
+
+    #[test]
+    fn unknown_wiki_links_are_found_at_their_offset() {
+        let (html, unknown) = wiki_html("*See* [[timer]] and [[nothing]]");
+        assert_eq!(html, "<p><em>See</em> [[timer]] and [[nothing]]</p>\n");
+        assert_eq!(unknown, vec![("timer".to_string(), 6), ("nothing".to_string(), 20)]);
+    }
+
+    #[test]
+    fn wiki_links_are_not_expanded_in_code() {
+        let (html, unknown) = wiki_html("`[[counter]]`\n\n```toml\n[[category]]\nname = \"basics\"\n```\n");
+        assert_eq!(
+            html,
+            "<p><code>[[counter]]</code></p>\n<pre><code class=\"language-toml\">[[category]]\nname = &quot;basics&quot;\n</code></pre>\n"
+        );
+        assert!(unknown.is_empty());
+    }
+
+    #[test]
+    fn notes_take_the_following_comments() {
+        let code = "\
+fn showcase() {
+    // NOTE: the first line
+    // and the second one
+    let x = 1;
+    // NOTE: next to the line after the marker
+    // lbe:focus
+    view!{}
+}";
+        let notes = notes(code);
+        assert_eq!(notes.len(), 2);
+        assert_eq!((notes[0].comment.clone(), notes[0].line), (1..3, 3));
+        assert_eq!(notes[0].markdown, "the first line\nand the second one");
+        assert_eq!((notes[1].comment.clone(), notes[1].line), (4..5, 6));
+        assert_eq!(notes[1].markdown, "next to the line after the marker");
+    }
+
+    /// the line and message of the error of `check_markers`
+    fn marker_error(source: &str) -> Option<(usize, String)> {
+        check_markers(Path::new("example.rs"), source)
+            .err()
+            .map(|d| (d.position.unwrap().0, d.message))
+    }
+
+    #[test]
+    fn hidden_regions_are_closed_and_not_nested() {
+        assert_eq!(marker_error("// lbe:hide-start\nuse leptos::*;\n// lbe:hide-end\n// lbe:focus"), None);
+        assert_eq!(
+            marker_error("// lbe:hide-start\n  // lbe:hide-start\n// lbe:hide-end"),
+            Some((2, "hidden regions cannot be nested, the one at line 1 is not closed".into()))
+        );
+        assert_eq!(
+            marker_error("fn f() {}\n// lbe:hide-end"),
+            Some((2, "`lbe:hide-end` without a `lbe:hide-start`".into()))
+        );
+        assert_eq!(
+            marker_error("\n// lbe:hide-start\nfn f() {}"),
+            Some((2, "`lbe:hide-start` without a `lbe:hide-end`".into()))
+        );
+    }
+
+    const SIGNALS: &str = "
+use leptos::*;
+
+pub fn showcase() -> impl IntoView {
+    let (count, set_count) = create_signal(0);
+    let double = move || count.get() * 2;
+    view! {
+        <button on:click=move |_| set_count.update(|n| *n += 1)>{count()}</button>
+        <p>{double()} {move || set_count(1)}</p>
+    }
+}";
+
+    #[test]
+    fn signals_called_like_functions_need_nightly() {
+        let positions: Vec<_> = check_signal_calls(Path::new("example.rs"), SIGNALS, &[])
+            .into_iter()
+            .map(|d| d.position.unwrap())
+            .collect();
+        // not `double`, a closure, nor the `update` method
+        assert_eq!(positions, vec![(8, 66), (9, 32)]);
+        assert!(check_signal_calls(Path::new("example.rs"), SIGNALS, &["nightly".to_string()]).is_empty());
+    }
+
+    #[test]
+    fn apis_are_found_with_their_crate() {
+        let source = "
+use leptos::*;
+use leptos_router::A;
+use other::Show;
+
+fn Counter() -> impl IntoView {
+    let memo = leptos::create_memo(|_| 1);
+    let create_effect = || ();
+    create_effect();
+    view! { <For/> <A/> <Show/> }
+}";
+        // `Show` is another crate's, and `create_effect` a local
+        assert_eq!(used_apis(&[source.to_string()]), vec!["view", "IntoView", "create_memo", "For", "A"]);
+    }
+
+    #[test]
+    fn documented_items_have_their_kind() {
+        let file = syn::parse_file("use leptos::*;\nfn f() { let s = create_signal(0); leptos::unknown(); }").unwrap();
+        let items = documented_items(Some(&file));
+        assert_eq!(items.get("create_signal"), Some(&("leptos", Some(ApiKind::Function))));
+        assert_eq!(items.get("unknown"), Some(&("leptos", None)));
+        assert_eq!(items.get("f"), None);
+    }
+}
@@ -1,6 +1,7 @@
 use leptos::*;
 use leptos_router::*;
 
+// lbe:focus
 #[component]
 pub fn SimpleQueryCounter() -> impl IntoView {
     let (count, set_count) = create_query_signal::<i32>("count");
@@ -20,6 +21,7 @@ pub fn SimpleQueryCounter() -> impl IntoView {
     }
 }
 
+// lbe:hide-start
 pub fn showcase() -> impl IntoView {
     view!{
         <Router>
@@ -27,3 +29,4 @@ pub fn showcase() -> impl IntoView {
         </Router>
     }
 }
+// lbe:hide-end
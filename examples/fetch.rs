@@ -40,7 +40,7 @@ async fn fetch_cats(count: CatCount) -> Result<Vec<String>> {
 pub fn showcase() -> impl IntoView {
     let (cat_count, set_cat_count) = create_signal::<CatCount>(0);
 
-    // we use local_resource here because
+    // NOTE: we use local_resource here because
     // 1) our error type isn't serializable/deserializable
     // 2) we're not doing server-side rendering in this example anyway
     //    (during SSR, create_resource will begin loading on the server and resolve on the client)
@@ -64,7 +64,7 @@ pub fn showcase() -> impl IntoView {
         }
     };
 
-    // the renderer can handle Option<_> and Result<_> states
+    // NOTE: the renderer can handle Option<_> and Result<_> states
     // by displaying nothing for None if the resource is still loading
     // and by using the ErrorBoundary fallback to catch Err(_)
     // so we'll just use `.and_then()` to map over the happy path
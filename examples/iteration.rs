@@ -1,10 +1,10 @@
 use leptos::*;
 
-// Iteration is a very common task in most applications.
+// NOTE: Iteration is a very common task in most applications.
 // So how do you take a list of data and render it in the DOM?
 // This example will show you the two ways:
 // 1) for mostly-static lists, using Rust iterators
-// 2) for lists that grow, shrink, or move items, using <For/>
+// 2) for lists that grow, shrink, or move items, using `<For/>`
 
 pub fn showcase() -> impl IntoView {
     view! {
@@ -28,7 +28,7 @@ fn StaticList(
     // create counter signals that start at incrementing numbers
     let counters = (1..=length).map(|idx| create_signal(idx));
 
-    // when you have a list that doesn't change, you can
+    // NOTE: when you have a list that doesn't change, you can
     // manipulate it using ordinary Rust iterators
     // and collect it into a Vec<_> to insert it into the DOM
     let counter_buttons = counters
@@ -45,7 +45,7 @@ fn StaticList(
         })
         .collect::<Vec<_>>();
 
-    // Note that if `counter_buttons` were a reactive list
+    // NOTE: if `counter_buttons` were a reactive list
     // and its value changed, this would be very inefficient:
     // it would rerender every row every time the list changed.
     view! {
@@ -60,8 +60,8 @@ fn DynamicList(
     /// The number of counters to begin with.
     initial_length: usize,
 ) -> impl IntoView {
-    // This dynamic list will use the <For/> component.
-    // <For/> is a keyed list. This means that each row
+    // NOTE: This dynamic list will use the `<For/>` component.
+    // `<For/>` is a keyed list. This means that each row
     // has a defined key. If the key does not change, the row
     // will not be re-rendered. When the list changes, only
     // the minimum number of changes will be made to the DOM.
@@ -108,7 +108,7 @@ fn DynamicList(
                     // this should usually be a signal or derived signal
                     // if it's not reactive, just render a Vec<_> instead of <For/>
                     each=counters
-                    // the key should be unique and stable for each row
+                    // NOTE: the key should be unique and stable for each row
                     // using an index is usually a bad idea, unless your list
                     // can only grow, because moving items around inside the list
                     // means their indices will change and they will all rerender
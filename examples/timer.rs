@@ -3,7 +3,7 @@ use std::time::Duration;
 
 /// Timer example, demonstrating the use of `use_interval`.
 pub fn showcase() -> impl IntoView {
-    // count_a updates with a fixed interval of 1000 ms, whereas count_b has a dynamic
+    // NOTE: count_a updates with a fixed interval of 1000 ms, whereas count_b has a dynamic
     // update interval.
     let (count_a, set_count_a) = create_signal(0_i32);
     let (count_b, set_count_b) = create_signal(0_i32);
@@ -25,7 +25,7 @@ pub fn showcase() -> impl IntoView {
             <div>{count_b}</div>
             <input prop:value=interval on:input=move |ev| {
                 if let Ok(value) = event_target_value(&ev).parse::<u64>() {
-                    set_interval(value);
+                    set_interval.set(value);
                 }
             }/>
         </div>
@@ -41,7 +41,7 @@ where
 {
     let interval_millis = interval_millis.into();
     create_effect(move |prev_handle: Option<IntervalHandle>| {
-        // effects get their previous return value as an argument
+        // NOTE: effects get their previous return value as an argument
         // each time the effect runs, it will return the interval handle
         // so if we have a previous one, we cancel it
         if let Some(prev_handle) = prev_handle {
@@ -51,7 +51,7 @@ where
         // here, we return the handle
         set_interval_with_handle(
             f.clone(),
-            // this is the only reactive access, so this effect will only
+            // NOTE: this is the only reactive access, so this effect will only
             // re-run when the interval changes
             Duration::from_millis(interval_millis.get()),
         )
@@ -0,0 +1,73 @@
+//! the view of a single todo
+
+use leptos::{html::Input, *};
+
+use super::model::{Todo, Todos};
+use super::{ENTER_KEY, ESCAPE_KEY};
+
+#[component]
+pub fn Todo(todo: Todo) -> impl IntoView {
+    let (editing, set_editing) = create_signal(false);
+    let set_todos = use_context::<WriteSignal<Todos>>().unwrap();
+
+    // this will be filled by node_ref=input below
+    let todo_input = create_node_ref::<Input>();
+
+    let save = move |value: &str| {
+        let value = value.trim();
+        if value.is_empty() {
+            set_todos.update(|t| t.remove(todo.id));
+        } else {
+            todo.title.set(value.to_string());
+        }
+        set_editing(false);
+    };
+
+    view! {
+        <li
+            class="todo"
+            class:editing={editing}
+            class:completed={move || todo.completed.get()}
+        >
+            <div class="view">
+                <input
+                    node_ref=todo_input
+                    class="toggle"
+                    type="checkbox"
+                    prop:checked={move || (todo.completed)()}
+                    on:input={move |ev| {
+                        let checked = event_target_checked(&ev);
+                        todo.completed.set(checked);
+                    }}
+                />
+                <label on:dblclick=move |_| {
+                    set_editing(true);
+
+                    if let Some(input) = todo_input.get() {
+                        _ = input.focus();
+                    }
+                }>
+                    {move || todo.title.get()}
+                </label>
+                <button class="destroy" on:click=move |_| set_todos.update(|t| t.remove(todo.id))/>
+            </div>
+            {move || editing().then(|| view! {
+                <input
+                    class="edit"
+                    class:hidden={move || !(editing)()}
+                    prop:value={move || todo.title.get()}
+                    on:focusout=move |ev: web_sys::FocusEvent| save(&event_target_value(&ev))
+                    on:keyup={move |ev: web_sys::KeyboardEvent| {
+                        let key_code = ev.key_code();
+                        if key_code == ENTER_KEY {
+                            save(&event_target_value(&ev));
+                        } else if key_code == ESCAPE_KEY {
+                            set_editing(false);
+                        }
+                    }}
+                />
+            })
+        }
+        </li>
+    }
+}
@@ -1,135 +1,12 @@
 use leptos::{html::Input, leptos_dom::helpers::location_hash, *};
 use leptos::logging::error;
-use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct Todos(pub Vec<Todo>);
+mod model;
+mod components;
 
-const STORAGE_KEY: &str = "todos-leptos";
-
-// Basic operations to manipulate the todo list: nothing really interesting here
-impl Todos {
-    pub fn new() -> Self {
-        let starting_todos =
-            window()
-                .local_storage()
-                .ok()
-                .flatten()
-                .and_then(|storage| {
-                    storage.get_item(STORAGE_KEY).ok().flatten().and_then(
-                        |value| serde_json::from_str::<Vec<Todo>>(&value).ok(),
-                    )
-                })
-                .unwrap_or_default();
-        Self(starting_todos)
-    }
-
-    pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
-    }
-
-    pub fn add(&mut self, todo: Todo) {
-        self.0.push(todo);
-    }
-
-    pub fn remove(&mut self, id: Uuid) {
-        self.retain(|todo| todo.id != id);
-    }
-
-    pub fn remaining(&self) -> usize {
-        // `todo.completed` is a signal, so we call .get() to access its value
-        self.0.iter().filter(|todo| !todo.completed.get()).count()
-    }
-
-    pub fn completed(&self) -> usize {
-        // `todo.completed` is a signal, so we call .get() to access its value
-        self.0.iter().filter(|todo| todo.completed.get()).count()
-    }
-
-    pub fn toggle_all(&self) {
-        // if all are complete, mark them all active
-        if self.remaining() == 0 {
-            for todo in &self.0 {
-                todo.completed.update(|completed| {
-                    if *completed {
-                        *completed = false
-                    }
-                });
-            }
-        }
-        // otherwise, mark them all complete
-        else {
-            for todo in &self.0 {
-                todo.completed.set(true);
-            }
-        }
-    }
-
-    fn clear_completed(&mut self) {
-        self.retain(|todo| !todo.completed.get());
-    }
-
-    fn retain(&mut self, mut f: impl FnMut(&Todo) -> bool) {
-        self.0.retain(|todo| {
-            let retain = f(todo);
-            // because these signals are created at the top level,
-            // they are owned by the <TodoMVC/> component and not
-            // by the individual <Todo/> components. This means
-            // that if they are not manually disposed when removed, they
-            // will be held onto until the <TodoMVC/> is unmounted.
-            if !retain {
-                todo.title.dispose();
-                todo.completed.dispose();
-            }
-            retain
-        })
-    }
-}
-
-impl Default for Todos {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
-pub struct Todo {
-    pub id: Uuid,
-    pub title: RwSignal<String>,
-    pub completed: RwSignal<bool>,
-}
-
-impl Todo {
-    pub fn new(id: Uuid, title: String) -> Self {
-        Self::new_with_completed(id, title, false)
-    }
-
-    pub fn new_with_completed(
-        id: Uuid,
-        title: String,
-        completed: bool,
-    ) -> Self {
-        // RwSignal combines the getter and setter in one struct, rather than separating
-        // the getter from the setter. This makes it more convenient in some cases, such
-        // as when we're putting the signals into a struct and passing it around. There's
-        // no real difference: you could use `create_signal` here, or use `create_rw_signal`
-        // everywhere.
-        let title = create_rw_signal(title);
-        let completed = create_rw_signal(completed);
-        Self {
-            id,
-            title,
-            completed,
-        }
-    }
-
-    pub fn toggle(&self) {
-        // A signal's `update()` function gives you a mutable reference to the current value
-        // You can use that to modify the value in place, which will notify any subscribers.
-        self.completed.update(|completed| *completed = !*completed);
-    }
-}
+use model::*;
+use components::*;
 
 const ESCAPE_KEY: u32 = 27;
 const ENTER_KEY: u32 = 13;
@@ -282,73 +159,6 @@ pub fn showcase() -> impl IntoView {
     }
 }
 
-#[component]
-pub fn Todo(todo: Todo) -> impl IntoView {
-    let (editing, set_editing) = create_signal(false);
-    let set_todos = use_context::<WriteSignal<Todos>>().unwrap();
-
-    // this will be filled by node_ref=input below
-    let todo_input = create_node_ref::<Input>();
-
-    let save = move |value: &str| {
-        let value = value.trim();
-        if value.is_empty() {
-            set_todos.update(|t| t.remove(todo.id));
-        } else {
-            todo.title.set(value.to_string());
-        }
-        set_editing(false);
-    };
-
-    view! {
-        <li
-            class="todo"
-            class:editing={editing}
-            class:completed={move || todo.completed.get()}
-        >
-            <div class="view">
-                <input
-                    node_ref=todo_input
-                    class="toggle"
-                    type="checkbox"
-                    prop:checked={move || (todo.completed)()}
-                    on:input={move |ev| {
-                        let checked = event_target_checked(&ev);
-                        todo.completed.set(checked);
-                    }}
-                />
-                <label on:dblclick=move |_| {
-                    set_editing(true);
-
-                    if let Some(input) = todo_input.get() {
-                        _ = input.focus();
-                    }
-                }>
-                    {move || todo.title.get()}
-                </label>
-                <button class="destroy" on:click=move |_| set_todos.update(|t| t.remove(todo.id))/>
-            </div>
-            {move || editing().then(|| view! {
-                <input
-                    class="edit"
-                    class:hidden={move || !(editing)()}
-                    prop:value={move || todo.title.get()}
-                    on:focusout=move |ev: web_sys::FocusEvent| save(&event_target_value(&ev))
-                    on:keyup={move |ev: web_sys::KeyboardEvent| {
-                        let key_code = ev.key_code();
-                        if key_code == ENTER_KEY {
-                            save(&event_target_value(&ev));
-                        } else if key_code == ESCAPE_KEY {
-                            set_editing(false);
-                        }
-                    }}
-                />
-            })
-        }
-        </li>
-    }
-}
-
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum Mode {
     Active,
@@ -0,0 +1,134 @@
+//! the state of the application: the list of todos,
+//! saved in the local storage of the browser
+
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Todos(pub Vec<Todo>);
+
+pub const STORAGE_KEY: &str = "todos-leptos";
+
+// Basic operations to manipulate the todo list: nothing really interesting here
+impl Todos {
+    pub fn new() -> Self {
+        let starting_todos =
+            window()
+                .local_storage()
+                .ok()
+                .flatten()
+                .and_then(|storage| {
+                    storage.get_item(STORAGE_KEY).ok().flatten().and_then(
+                        |value| serde_json::from_str::<Vec<Todo>>(&value).ok(),
+                    )
+                })
+                .unwrap_or_default();
+        Self(starting_todos)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn add(&mut self, todo: Todo) {
+        self.0.push(todo);
+    }
+
+    pub fn remove(&mut self, id: Uuid) {
+        self.retain(|todo| todo.id != id);
+    }
+
+    pub fn remaining(&self) -> usize {
+        // `todo.completed` is a signal, so we call .get() to access its value
+        self.0.iter().filter(|todo| !todo.completed.get()).count()
+    }
+
+    pub fn completed(&self) -> usize {
+        // `todo.completed` is a signal, so we call .get() to access its value
+        self.0.iter().filter(|todo| todo.completed.get()).count()
+    }
+
+    pub fn toggle_all(&self) {
+        // if all are complete, mark them all active
+        if self.remaining() == 0 {
+            for todo in &self.0 {
+                todo.completed.update(|completed| {
+                    if *completed {
+                        *completed = false
+                    }
+                });
+            }
+        }
+        // otherwise, mark them all complete
+        else {
+            for todo in &self.0 {
+                todo.completed.set(true);
+            }
+        }
+    }
+
+    pub fn clear_completed(&mut self) {
+        self.retain(|todo| !todo.completed.get());
+    }
+
+    fn retain(&mut self, mut f: impl FnMut(&Todo) -> bool) {
+        self.0.retain(|todo| {
+            let retain = f(todo);
+            // because these signals are created at the top level,
+            // they are owned by the <TodoMVC/> component and not
+            // by the individual <Todo/> components. This means
+            // that if they are not manually disposed when removed, they
+            // will be held onto until the <TodoMVC/> is unmounted.
+            if !retain {
+                todo.title.dispose();
+                todo.completed.dispose();
+            }
+            retain
+        })
+    }
+}
+
+impl Default for Todos {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Todo {
+    pub id: Uuid,
+    pub title: RwSignal<String>,
+    pub completed: RwSignal<bool>,
+}
+
+impl Todo {
+    pub fn new(id: Uuid, title: String) -> Self {
+        Self::new_with_completed(id, title, false)
+    }
+
+    pub fn new_with_completed(
+        id: Uuid,
+        title: String,
+        completed: bool,
+    ) -> Self {
+        // RwSignal combines the getter and setter in one struct, rather than separating
+        // the getter from the setter. This makes it more convenient in some cases, such
+        // as when we're putting the signals into a struct and passing it around. There's
+        // no real difference: you could use `create_signal` here, or use `create_rw_signal`
+        // everywhere.
+        let title = create_rw_signal(title);
+        let completed = create_rw_signal(completed);
+        Self {
+            id,
+            title,
+            completed,
+        }
+    }
+
+    pub fn toggle(&self) {
+        // A signal's `update()` function gives you a mutable reference to the current value
+        // You can use that to modify the value in place, which will notify any subscribers.
+        self.completed.update(|completed| *completed = !*completed);
+    }
+}
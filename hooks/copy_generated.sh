@@ -0,0 +1,21 @@
+#!/bin/sh
+# the post_build hook of Trunk.toml: copies the files build.rs generates next to the app.
+# The pages of `pages/` link the files of the app by their names without the hash
+# trunk adds, `style.css` for `style-0123456789abcdef.css`: they are renamed here
+set -e
+cd "$TRUNK_SOURCE_DIR"
+cp -r highlighted pages "$TRUNK_STAGING_DIR"
+
+renames=""
+for file in "$TRUNK_STAGING_DIR"/*; do
+    hashed=$(basename "$file")
+    name=$(echo "$hashed" | sed -E 's/-[0-9a-f]+([._])/\1/')
+    if [ "$name" != "$hashed" ]; then
+        renames="$renames s|\"\./$(echo "$name" | sed 's/\./\\./g')\"|\"./$hashed\"|g;"
+    fi
+done
+
+for page in "$TRUNK_STAGING_DIR"/pages/*.html; do
+    sed "$renames" "$page" > "$page.tmp"
+    mv "$page.tmp" "$page"
+done
@@ -0,0 +1,111 @@
+/// the part of the url after `#`, that says what is shown:
+/// `counter`, `timer:L12`, `todomvc:L137-L160`,
+/// or `todomvc:model.rs:L4-L9` for another file than the first one.
+/// For the API index, the second part is the selected item: `api-index:For`
+#[derive(Clone, PartialEq, Debug)]
+pub struct Anchor {
+    pub name: String,
+    /// the path of the file, as shown in its tab
+    pub file: Option<String>,
+    /// first and last line, starting at 1
+    pub lines: Option<(usize, usize)>,
+}
+
+/// `L12` or `L12-L20`
+fn parse_lines(s: &str) -> Option<(usize, usize)> {
+    let line = |s: &str| s.strip_prefix('L')?.parse::<usize>().ok().filter(|n| *n > 0);
+    match s.split_once('-') {
+        Some((start, end)) => {
+            let (start, end) = (line(start)?, line(end)?);
+            Some((start.min(end), start.max(end)))
+        },
+        None => line(s).map(|n| (n, n)),
+    }
+}
+
+impl Anchor {
+    pub fn parse(hash: &str) -> Self {
+        let mut parts: Vec<_> = hash.split(':').collect();
+        let lines = match parts.last().and_then(|s| parse_lines(s)) {
+            Some(lines) if parts.len() > 1 => {
+                parts.pop();
+                Some(lines)
+            },
+            _ => None,
+        };
+        let name = parts.remove(0).to_string();
+        let file = (!parts.is_empty()).then(|| parts.join(":"));
+        Anchor { name, file, lines }
+    }
+
+    /// the lines `start..=end` of `file` in the example `name`
+    pub fn lines(name: &str, file: Option<&str>, (start, end): (usize, usize)) -> Self {
+        Anchor {
+            name: name.to_string(),
+            file: file.map(str::to_string),
+            lines: Some((start, end)),
+        }
+    }
+
+    /// the inverse of `parse`, without the `#`
+    pub fn to_hash(&self) -> String {
+        let mut hash = self.name.clone();
+        if let Some(file) = &self.file {
+            hash.push(':');
+            hash.push_str(file);
+        }
+        match self.lines {
+            Some((start, end)) if start == end => hash.push_str(&format!(":L{start}")),
+            Some((start, end)) => hash.push_str(&format!(":L{start}-L{end}")),
+            None => (),
+        }
+        hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anchor(name: &str, file: Option<&str>, lines: Option<(usize, usize)>) -> Anchor {
+        Anchor { name: name.into(), file: file.map(str::to_string), lines }
+    }
+
+    #[test]
+    fn round_trips() {
+        for (hash, expected) in [
+            ("counter", anchor("counter", None, None)),
+            ("timer:L12", anchor("timer", None, Some((12, 12)))),
+            ("todomvc:L137-L160", anchor("todomvc", None, Some((137, 160)))),
+            ("todomvc:model.rs:L4-L9", anchor("todomvc", Some("model.rs"), Some((4, 9)))),
+            ("todomvc:model.rs", anchor("todomvc", Some("model.rs"), None)),
+            ("api-index:For", anchor("api-index", Some("For"), None)),
+        ] {
+            let parsed = Anchor::parse(hash);
+            assert_eq!(parsed, expected, "{hash}");
+            assert_eq!(parsed.to_hash(), hash);
+        }
+    }
+
+    #[test]
+    fn reversed_ranges_are_put_in_order() {
+        let parsed = Anchor::parse("todomvc:model.rs:L9-L4");
+        assert_eq!(parsed, anchor("todomvc", Some("model.rs"), Some((4, 9))));
+        assert_eq!(parsed.to_hash(), "todomvc:model.rs:L4-L9");
+    }
+
+    #[test]
+    fn malformed_lines_are_part_of_the_file() {
+        for (hash, expected) in [
+            ("timer:L0", anchor("timer", Some("L0"), None)),
+            ("timer:Lx", anchor("timer", Some("Lx"), None)),
+            ("timer:L4-", anchor("timer", Some("L4-"), None)),
+            ("timer:12", anchor("timer", Some("12"), None)),
+            // a single part is always a name
+            ("L12", anchor("L12", None, None)),
+            ("", anchor("", None, None)),
+        ] {
+            assert_eq!(Anchor::parse(hash), expected, "{hash}");
+        }
+    }
+}
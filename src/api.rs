@@ -0,0 +1,62 @@
+use leptos::*;
+
+use super::examples::APIS;
+use super::anchor::Anchor;
+
+/// the url of the API index is `#api-index`, or `#api-index:create_signal`
+/// to show one of the items.
+/// Example names are rust modules, so none of them can be `api-index`
+pub const API_INDEX: &str = "api-index";
+
+/// links to the entries of the API index
+#[component]
+pub fn ApiChips(apis: &'static [&'static str]) -> impl IntoView {
+    view!{
+        <div class="api-chips">
+            {apis.iter()
+                .map(|name| view!{
+                    <a class="chip" href=format!("#{API_INDEX}:{name}")>{*name}</a>
+                })
+                .collect_view()
+            }
+        </div>
+    }
+}
+
+/// every leptos item used by the examples, with the examples that show it
+#[component]
+pub fn ApiIndex(anchor: Memo<Option<Anchor>>) -> impl IntoView {
+    let selected = move || anchor.get().and_then(|a| a.file);
+
+    create_effect(move |_| if let Some(name) = selected() {
+        request_animation_frame(move || {
+            if let Some(entry) = document().get_element_by_id(&format!("api-{name}")) {
+                entry.scroll_into_view();
+            }
+        });
+    });
+
+    view!{
+        <ul class="api-index">
+            {APIS.iter()
+                .map(|api| view!{
+                    <li
+                        id=format!("api-{}", api.name)
+                        class:selected=move || selected().as_deref() == Some(api.name)
+                    >
+                        <code>{api.name}</code>
+                        " "
+                        <span class="api-kind">{api.kind.name()}</span>
+                        <ul>
+                            {api.examples.iter()
+                                .map(|e| view!{<li><a href=format!("#{e}")>{*e}</a></li>})
+                                .collect_view()
+                            }
+                        </ul>
+                    </li>
+                })
+                .collect_view()
+            }
+        </ul>
+    }
+}
@@ -1,192 +0,0 @@
-//! generated automatically by build.rs
-mod counter {
-    include!("../examples/counter.rs");
-}
-mod hello_world {
-    include!("../examples/hello_world.rs");
-}
-mod counter_without_macro {
-    include!("../examples/counter_without_macro.rs");
-}
-mod error_boundary {
-    include!("../examples/error_boundary.rs");
-}
-mod fetch {
-    include!("../examples/fetch.rs");
-}
-mod html_callback {
-    include!("../examples/html_callback.rs");
-}
-mod timer {
-    include!("../examples/timer.rs");
-}
-mod maybe_signal {
-    include!("../examples/maybe_signal.rs");
-}
-mod todomvc {
-    include!("../examples/todomvc.rs");
-}
-mod iteration {
-    include!("../examples/iteration.rs");
-}
-mod dynamic_font {
-    include!("../examples/dynamic_font.rs");
-}
-mod counter_url_query {
-    include!("../examples/counter_url_query.rs");
-}
-use super::{Example, pack_example};
-pub const N_EXAMPLES: usize = 12usize;
-pub type Examples = std::collections::HashMap<&'static str, std::rc::Rc<Example>>;
-pub fn examples() -> Examples {
-    [
-        Example {
-            name: "counter",
-            source: "use leptos::*;\n\n#[component]\npub fn SimpleCounter(initial_value: i32) -> impl IntoView {\n    // create a reactive signal with the initial value\n    let (value, set_value) = create_signal(initial_value);\n\n    // create event handlers for our buttons\n    // note that `value` and `set_value` are `Copy`, so it's super easy to move them into closures\n    let clear = move |_| set_value(0);\n    let decrement = move |_| set_value.update(|value| *value -= 1);\n    let increment = move |_| set_value.update(|value| *value += 1);\n\n    // create user interfaces with the declarative `view!` macro\n    view! {\n        <div>\n            <button on:click=clear>Clear</button>\n            <button on:click=decrement>-1</button>\n            // text nodes can be quoted or unquoted\n            <span>\"Value: \" {value} \"!\"</span>\n            <button on:click=increment>+1</button>\n        </div>\n    }\n}\n\npub fn showcase() -> impl IntoView {\n    view!{\n        <SimpleCounter initial_value=0/>\n    }\n}\n",
-            highlighted_source: "<pre style=\"background-color:#eff1f5;\">\n<span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">leptos::*;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">#[</span><span style=\"color:#bf616a;\">component</span><span style=\"color:#4f5b66;\">]\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">SimpleCounter</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#bf616a;\">initial_value</span><span style=\"color:#4f5b66;\">: </span><span style=\"color:#b48ead;\">i32</span><span style=\"color:#4f5b66;\">) -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// create a reactive signal with the initial value\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let </span><span style=\"color:#4f5b66;\">(value, set_value) = </span><span style=\"color:#96b5b4;\">create_signal</span><span style=\"color:#4f5b66;\">(initial_value);\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// create event handlers for our buttons\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// note that `value` and `set_value` are `Copy`, so it&#39;s super easy to move them into closures\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> clear = </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| </span><span style=\"color:#96b5b4;\">set_value</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">0</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> decrement = </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| set_value.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">value</span><span style=\"color:#4f5b66;\">| *value -= </span><span style=\"color:#d08770;\">1</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> increment = </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| set_value.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">value</span><span style=\"color:#4f5b66;\">| *value += </span><span style=\"color:#d08770;\">1</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// create user interfaces with the declarative `view!` macro\n</span><span style=\"color:#4f5b66;\">    view! {\n</span><span style=\"color:#4f5b66;\">        &lt;div&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;button on:click=clear&gt;Clear&lt;</span><span style=\"background-color:#bf616a;color:#eff1f5;\">/</span><span style=\"color:#4f5b66;\">button&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;button on:click=decrement&gt;-</span><span style=\"color:#d08770;\">1</span><span style=\"color:#4f5b66;\">&lt;/button&gt;\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#a7adba;\">// text nodes can be quoted or unquoted\n</span><span style=\"color:#4f5b66;\">            &lt;span&gt;&quot;</span><span style=\"color:#a3be8c;\">Value: </span><span style=\"color:#4f5b66;\">&quot; {value} &quot;</span><span style=\"color:#a3be8c;\">!</span><span style=\"color:#4f5b66;\">&quot;&lt;/span&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;button on:click=increment&gt;+</span><span style=\"color:#d08770;\">1</span><span style=\"color:#4f5b66;\">&lt;/button&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;/div&gt;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">showcase</span><span style=\"color:#4f5b66;\">() -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    view!{\n</span><span style=\"color:#4f5b66;\">        &lt;SimpleCounter initial_value=</span><span style=\"color:#d08770;\">0</span><span style=\"color:#4f5b66;\">/&gt;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span></pre>\n",
-            code: pack_example(counter::showcase),
-            css: stylist::style!("").unwrap(),
-            description: "A simpler counter component.\n",
-            motivation: "<p>This example shows how to write the most basic component.</p>\n<ul>\n<li>the component owns a state (the count)</li>\n<li>some browser events (onclick in this case) will update this state</li>\n<li>each time the state change, it will be rerendered</li>\n</ul>\n",
-            related: Some(
-                "<p>See <a href=\"#counter_without_macro\">counter_without_macro</a> and <a href=\"#counter_url_query\">counter_url_query</a> for other versions of the same component</p>\n",
-            ),
-        },
-        Example {
-            name: "hello_world",
-            source: "use leptos::*;\n\npub fn showcase() -> impl IntoView {\n    view!{\n        \"hello world !\"\n    }\n}\n",
-            highlighted_source: "<pre style=\"background-color:#eff1f5;\">\n<span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">leptos::*;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">showcase</span><span style=\"color:#4f5b66;\">() -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    view!{\n</span><span style=\"color:#4f5b66;\">        &quot;</span><span style=\"color:#a3be8c;\">hello world !</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span></pre>\n",
-            code: pack_example(hello_world::showcase),
-            css: stylist::style!("").unwrap(),
-            description: "the most simple `hello world` code",
-            motivation: "<p>In this example, you can see the bare minimum that is needed to work with leptos.\nyou import all the leptos functionnality, then define a function that returns any html (a <code>View</code>)</p>\n<p>Note that to actually render it in your browser, you have to mount it first</p>\n<pre><code class=\"language-rust\">fn main(){\n    mount_to_body(showcase);\n}\n</code></pre>\n",
-            related: Some(
-                "<p><a href=\"https://leptos-rs.github.io/leptos/02_getting_started.html\">https://leptos-rs.github.io/leptos/02_getting_started.html</a></p>\n",
-            ),
-        },
-        Example {
-            name: "counter_without_macro",
-            source: "use leptos::{ev, html::*, *};\n\n/// A simple counter view.\n// A component is really just a function call: it runs once to create the DOM and reactive system\npub fn counter(initial_value: i32, step: u32) -> impl IntoView {\n    let count = RwSignal::new(Count::new(initial_value, step));\n\n    // the function name is the same as the HTML tag name\n    div()\n        // children can be added with .child()\n        // this takes any type that implements IntoView as its argument\n        // for example, a string or an HtmlElement<_>\n        // it can also take an array of types that impl IntoView\n        // or a tuple of up to 26 objects that impl IntoView\n        .child((\n            button()\n                // typed events found in leptos::ev\n                // 1) prevent typos in event names\n                // 2) allow for correct type inference in callbacks\n                .on(ev::click, move |_| count.update(Count::clear))\n                .child(\"Clear\"),\n            button()\n                .on(ev::click, move |_| count.update(Count::decrease))\n                .child(\"-1\"),\n            span().child((\"Value: \", move || count.get().value(), \"!\")),\n            button()\n                .on(ev::click, move |_| count.update(Count::increase))\n                .child(\"+1\"),\n        ))\n}\n\n#[derive(Debug, Clone)]\npub struct Count {\n    value: i32,\n    step: i32,\n}\n\nimpl Count {\n    pub fn new(value: i32, step: u32) -> Self {\n        Count {\n            value,\n            step: step as i32,\n        }\n    }\n\n    pub fn value(&self) -> i32 {\n        self.value\n    }\n\n    pub fn increase(&mut self) {\n        self.value += self.step;\n    }\n\n    pub fn decrease(&mut self) {\n        self.value += -self.step;\n    }\n\n    pub fn clear(&mut self) {\n        self.value = 0;\n    }\n}\n\npub fn showcase() -> impl IntoView {\n    counter(0, 1)\n}\n",
-            highlighted_source: "<pre style=\"background-color:#eff1f5;\">\n<span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">leptos::{ev, html::*, *};\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#a7adba;\">/// A simple counter view.\n</span><span style=\"color:#a7adba;\">// A component is really just a function call: it runs once to create the DOM and reactive system\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">counter</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#bf616a;\">initial_value</span><span style=\"color:#4f5b66;\">: </span><span style=\"color:#b48ead;\">i32</span><span style=\"color:#4f5b66;\">, </span><span style=\"color:#bf616a;\">step</span><span style=\"color:#4f5b66;\">: </span><span style=\"color:#b48ead;\">u32</span><span style=\"color:#4f5b66;\">) -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> count = RwSignal::new(Count::new(initial_value, step));\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// the function name is the same as the HTML tag name\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#96b5b4;\">div</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// children can be added with .child()\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// this takes any type that implements IntoView as its argument\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// for example, a string or an HtmlElement&lt;_&gt;\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// it can also take an array of types that impl IntoView\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// or a tuple of up to 26 objects that impl IntoView\n</span><span style=\"color:#4f5b66;\">        .</span><span style=\"color:#96b5b4;\">child</span><span style=\"color:#4f5b66;\">((\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#96b5b4;\">button</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                </span><span style=\"color:#a7adba;\">// typed events found in leptos::ev\n</span><span style=\"color:#4f5b66;\">                </span><span style=\"color:#a7adba;\">// 1) prevent typos in event names\n</span><span style=\"color:#4f5b66;\">                </span><span style=\"color:#a7adba;\">// 2) allow for correct type inference in callbacks\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">on</span><span style=\"color:#4f5b66;\">(ev::click, </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| count.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(Count::clear))\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">child</span><span style=\"color:#4f5b66;\">(&quot;</span><span style=\"color:#a3be8c;\">Clear</span><span style=\"color:#4f5b66;\">&quot;),\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#96b5b4;\">button</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">on</span><span style=\"color:#4f5b66;\">(ev::click, </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| count.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(Count::decrease))\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">child</span><span style=\"color:#4f5b66;\">(&quot;</span><span style=\"color:#a3be8c;\">-1</span><span style=\"color:#4f5b66;\">&quot;),\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#96b5b4;\">span</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">child</span><span style=\"color:#4f5b66;\">((&quot;</span><span style=\"color:#a3be8c;\">Value: </span><span style=\"color:#4f5b66;\">&quot;, </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| count.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">value</span><span style=\"color:#4f5b66;\">(), &quot;</span><span style=\"color:#a3be8c;\">!</span><span style=\"color:#4f5b66;\">&quot;)),\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#96b5b4;\">button</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">on</span><span style=\"color:#4f5b66;\">(ev::click, </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| count.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(Count::increase))\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">child</span><span style=\"color:#4f5b66;\">(&quot;</span><span style=\"color:#a3be8c;\">+1</span><span style=\"color:#4f5b66;\">&quot;),\n</span><span style=\"color:#4f5b66;\">        ))\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">#[</span><span style=\"color:#bf616a;\">derive</span><span style=\"color:#4f5b66;\">(Debug, Clone)]\n</span><span style=\"color:#b48ead;\">pub struct </span><span style=\"color:#4f5b66;\">Count {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#bf616a;\">value</span><span style=\"color:#4f5b66;\">: </span><span style=\"color:#b48ead;\">i32</span><span style=\"color:#4f5b66;\">,\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#bf616a;\">step</span><span style=\"color:#4f5b66;\">: </span><span style=\"color:#b48ead;\">i32</span><span style=\"color:#4f5b66;\">,\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">impl </span><span style=\"color:#4f5b66;\">Count {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">new</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#bf616a;\">value</span><span style=\"color:#4f5b66;\">: </span><span style=\"color:#b48ead;\">i32</span><span style=\"color:#4f5b66;\">, </span><span style=\"color:#bf616a;\">step</span><span style=\"color:#4f5b66;\">: </span><span style=\"color:#b48ead;\">u32</span><span style=\"color:#4f5b66;\">) -&gt; </span><span style=\"color:#b48ead;\">Self </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">        Count {\n</span><span style=\"color:#4f5b66;\">            value,\n</span><span style=\"color:#4f5b66;\">            step: step as </span><span style=\"color:#b48ead;\">i32</span><span style=\"color:#4f5b66;\">,\n</span><span style=\"color:#4f5b66;\">        }\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">value</span><span style=\"color:#4f5b66;\">(&amp;</span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">) -&gt; </span><span style=\"color:#b48ead;\">i32 </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.value\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">increase</span><span style=\"color:#4f5b66;\">(&amp;</span><span style=\"color:#b48ead;\">mut </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">) {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.value += </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.step;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">decrease</span><span style=\"color:#4f5b66;\">(&amp;</span><span style=\"color:#b48ead;\">mut </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">) {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.value += -</span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.step;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">clear</span><span style=\"color:#4f5b66;\">(&amp;</span><span style=\"color:#b48ead;\">mut </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">) {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.value = </span><span style=\"color:#d08770;\">0</span><span style=\"color:#4f5b66;\">;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">showcase</span><span style=\"color:#4f5b66;\">() -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#96b5b4;\">counter</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">0</span><span style=\"color:#4f5b66;\">, </span><span style=\"color:#d08770;\">1</span><span style=\"color:#4f5b66;\">)\n</span><span style=\"color:#4f5b66;\">}\n</span></pre>\n",
-            code: pack_example(counter_without_macro::showcase),
-            css: stylist::style!("").unwrap(),
-            description: "simple counter component but without macros",
-            motivation: "<p>You can use the builder pattern if you don't feel like using a macro\nThis is the way the <code>view!</code> macro works, if you're curious</p>\n",
-            related: Some(
-                "<p>for the same example using macros, see <a href=\"#counter\">counter</a></p>\n",
-            ),
-        },
-        Example {
-            name: "error_boundary",
-            source: "use leptos::*;\n\npub fn showcase() -> impl IntoView {\n    let (value, set_value) = create_signal(Ok(0));\n\n    // when input changes, try to parse a number from the input\n    let on_input = move |ev| set_value(event_target_value(&ev).parse::<i32>());\n\n    view! {\n        <h1>\"Error Handling\"</h1>\n        <label>\n            \"Type a number (or something that's not a number!)\"\n            <input type=\"number\" on:input=on_input/>\n            // If an `Err(_) had been rendered inside the <ErrorBoundary/>,\n            // the fallback will be displayed. Otherwise, the children of the\n            // <ErrorBoundary/> will be displayed.\n            <ErrorBoundary\n                // the fallback receives a signal containing current errors\n                fallback=|errors| view! {\n                    <div class=\"error\">\n                        <p>\"Not a number! Errors: \"</p>\n                        // we can render a list of errors\n                        // as strings, if we'd like\n                        <ul>\n                            {move || errors.get()\n                                .into_iter()\n                                .map(|(_, e)| view! { <li>{e.to_string()}</li>})\n                                .collect_view()\n                            }\n                        </ul>\n                    </div>\n                }\n            >\n                <p>\n                    \"You entered \"\n                    // because `value` is `Result<i32, _>`,\n                    // it will render the `i32` if it is `Ok`,\n                    // and render nothing and trigger the error boundary\n                    // if it is `Err`. It's a signal, so this will dynamically\n                    // update when `value` changes\n                    <strong>{value}</strong>\n                </p>\n            </ErrorBoundary>\n        </label>\n    }\n}\n",
-            highlighted_source: "<pre style=\"background-color:#eff1f5;\">\n<span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">leptos::*;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">showcase</span><span style=\"color:#4f5b66;\">() -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let </span><span style=\"color:#4f5b66;\">(value, set_value) = </span><span style=\"color:#96b5b4;\">create_signal</span><span style=\"color:#4f5b66;\">(Ok(</span><span style=\"color:#d08770;\">0</span><span style=\"color:#4f5b66;\">));\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// when input changes, try to parse a number from the input\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> on_input = </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|ev| </span><span style=\"color:#96b5b4;\">set_value</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#96b5b4;\">event_target_value</span><span style=\"color:#4f5b66;\">(&amp;ev).parse::&lt;</span><span style=\"color:#b48ead;\">i32</span><span style=\"color:#4f5b66;\">&gt;());\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    view! {\n</span><span style=\"color:#4f5b66;\">        &lt;h1&gt;&quot;</span><span style=\"color:#a3be8c;\">Error Handling</span><span style=\"color:#4f5b66;\">&quot;&lt;/h1&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;label&gt;\n</span><span style=\"color:#4f5b66;\">            &quot;</span><span style=\"color:#a3be8c;\">Type a number (or something that&#39;s not a number!)</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">            &lt;input </span><span style=\"color:#b48ead;\">type</span><span style=\"color:#4f5b66;\">=&quot;</span><span style=\"color:#a3be8c;\">number</span><span style=\"color:#4f5b66;\">&quot; on:input=on_input/&gt;\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#a7adba;\">// If an `Err(_) had been rendered inside the &lt;ErrorBoundary/&gt;,\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#a7adba;\">// the fallback will be displayed. Otherwise, the children of the\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#a7adba;\">// &lt;ErrorBoundary/&gt; will be displayed.\n</span><span style=\"color:#4f5b66;\">            &lt;ErrorBoundary\n</span><span style=\"color:#4f5b66;\">                </span><span style=\"color:#a7adba;\">// the fallback receives a signal containing current errors\n</span><span style=\"color:#4f5b66;\">                </span><span style=\"color:#8fa1b3;\">fallback</span><span style=\"color:#4f5b66;\">=|</span><span style=\"color:#bf616a;\">errors</span><span style=\"color:#4f5b66;\">| view! {\n</span><span style=\"color:#4f5b66;\">                    &lt;div class=&quot;</span><span style=\"color:#a3be8c;\">error</span><span style=\"color:#4f5b66;\">&quot;&gt;\n</span><span style=\"color:#4f5b66;\">                        &lt;p&gt;&quot;</span><span style=\"color:#a3be8c;\">Not a number! Errors: </span><span style=\"color:#4f5b66;\">&quot;&lt;/p&gt;\n</span><span style=\"color:#4f5b66;\">                        </span><span style=\"color:#a7adba;\">// we can render a list of errors\n</span><span style=\"color:#4f5b66;\">                        </span><span style=\"color:#a7adba;\">// as strings, if we&#39;d like\n</span><span style=\"color:#4f5b66;\">                        &lt;ul&gt;\n</span><span style=\"color:#4f5b66;\">                            {</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| errors.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                                .</span><span style=\"color:#96b5b4;\">into_iter</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                                .</span><span style=\"color:#96b5b4;\">map</span><span style=\"color:#4f5b66;\">(|(_, </span><span style=\"color:#bf616a;\">e</span><span style=\"color:#4f5b66;\">)| view! { &lt;li&gt;{e.</span><span style=\"color:#96b5b4;\">to_string</span><span style=\"color:#4f5b66;\">()}&lt;/li&gt;})\n</span><span style=\"color:#4f5b66;\">                                .</span><span style=\"color:#96b5b4;\">collect_view</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                            }\n</span><span style=\"color:#4f5b66;\">                        &lt;/ul&gt;\n</span><span style=\"color:#4f5b66;\">                    &lt;/div&gt;\n</span><span style=\"color:#4f5b66;\">                }\n</span><span style=\"color:#4f5b66;\">            &gt;\n</span><span style=\"color:#4f5b66;\">                &lt;p&gt;\n</span><span style=\"color:#4f5b66;\">                    &quot;</span><span style=\"color:#a3be8c;\">You entered </span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#a7adba;\">// because `value` is `Result&lt;i32, _&gt;`,\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#a7adba;\">// it will render the `i32` if it is `Ok`,\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#a7adba;\">// and render nothing and trigger the error boundary\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#a7adba;\">// if it is `Err`. It&#39;s a signal, so this will dynamically\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#a7adba;\">// update when `value` changes\n</span><span style=\"color:#4f5b66;\">                    &lt;strong&gt;{value}&lt;/strong&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;/p&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;/ErrorBoundary&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;/label&gt;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span></pre>\n",
-            code: pack_example(error_boundary::showcase),
-            css: stylist::style!("").unwrap(),
-            description: "illustrates how to show a custom error message to the user",
-            motivation: "<p>To catch an error emmited by one of the children, you can use <code>ErrorBoundary</code></p>\n",
-            related: Some(
-                "<p><a href=\"https://leptos-rs.github.io/leptos/view/07_errors.html?highlight=error%20hnd#error-handling\">https://leptos-rs.github.io/leptos/view/07_errors.html?highlight=error%20hnd#error-handling</a></p>\n",
-            ),
-        },
-        Example {
-            name: "fetch",
-            source: "use leptos::{error::Result, *};\nuse serde::{Deserialize, Serialize};\nuse thiserror::Error;\n\n#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]\npub struct Cat {\n    url: String,\n}\n\n#[derive(Error, Clone, Debug)]\npub enum CatError {\n    #[error(\"Please request more than zero cats.\")]\n    NonZeroCats,\n}\n\ntype CatCount = usize;\n\nasync fn fetch_cats(count: CatCount) -> Result<Vec<String>> {\n    if count > 0 {\n        // make the request\n        let res = reqwasm::http::Request::get(&format!(\n            \"https://api.thecatapi.com/v1/images/search?limit={count}\",\n        ))\n        .send()\n        .await?\n        // convert it to JSON\n        .json::<Vec<Cat>>()\n        .await?\n        // extract the URL field for each cat\n        .into_iter()\n        .take(count)\n        .map(|cat| cat.url)\n        .collect::<Vec<_>>();\n        Ok(res)\n    } else {\n        Err(CatError::NonZeroCats.into())\n    }\n}\n\npub fn showcase() -> impl IntoView {\n    let (cat_count, set_cat_count) = create_signal::<CatCount>(0);\n\n    // we use local_resource here because\n    // 1) our error type isn't serializable/deserializable\n    // 2) we're not doing server-side rendering in this example anyway\n    //    (during SSR, create_resource will begin loading on the server and resolve on the client)\n    let cats = create_local_resource(cat_count, fetch_cats);\n\n    let fallback = move |errors: RwSignal<Errors>| {\n        let error_list = move || {\n            errors.with(|errors| {\n                errors\n                    .iter()\n                    .map(|(_, e)| view! { <li>{e.to_string()}</li> })\n                    .collect_view()\n            })\n        };\n\n        view! {\n            <div class=\"error\">\n                <h2>\"Error\"</h2>\n                <ul>{error_list}</ul>\n            </div>\n        }\n    };\n\n    // the renderer can handle Option<_> and Result<_> states\n    // by displaying nothing for None if the resource is still loading\n    // and by using the ErrorBoundary fallback to catch Err(_)\n    // so we'll just use `.and_then()` to map over the happy path\n    let cats_view = move || {\n        cats.and_then(|data| {\n            data.iter()\n                .map(|s| view! { <p><img src={s}/></p> })\n                .collect_view()\n        })\n    };\n\n    view! {\n        <div>\n            <label>\n                \"How many cats would you like?\"\n                <input\n                    type=\"number\"\n                    prop:value=move || cat_count.get().to_string()\n                    on:input=move |ev| {\n                        let val = event_target_value(&ev).parse::<CatCount>().unwrap_or(0);\n                        set_cat_count(val);\n                    }\n                />\n            </label>\n            <ErrorBoundary fallback>\n                <Transition fallback=move || {\n                    view! { <div>\"Loading (Suspense Fallback)...\"</div> }\n                }>\n                <div>\n                    {cats_view}\n                </div>\n                </Transition>\n            </ErrorBoundary>\n        </div>\n    }\n}\n",
-            highlighted_source: "<pre style=\"background-color:#eff1f5;\">\n<span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">leptos::{error::Result, *};\n</span><span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">serde::{Deserialize, Serialize};\n</span><span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">thiserror::Error;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">#[</span><span style=\"color:#bf616a;\">derive</span><span style=\"color:#4f5b66;\">(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]\n</span><span style=\"color:#b48ead;\">pub struct </span><span style=\"color:#4f5b66;\">Cat {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#bf616a;\">url</span><span style=\"color:#4f5b66;\">: String,\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">#[</span><span style=\"color:#bf616a;\">derive</span><span style=\"color:#4f5b66;\">(Error, Clone, Debug)]\n</span><span style=\"color:#b48ead;\">pub enum </span><span style=\"color:#4f5b66;\">CatError {\n</span><span style=\"color:#4f5b66;\">    #[</span><span style=\"color:#bf616a;\">error</span><span style=\"color:#4f5b66;\">(&quot;</span><span style=\"color:#a3be8c;\">Please request more than zero cats.</span><span style=\"color:#4f5b66;\">&quot;)]\n</span><span style=\"color:#4f5b66;\">    NonZeroCats,\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">type </span><span style=\"color:#4f5b66;\">CatCount = </span><span style=\"color:#b48ead;\">usize</span><span style=\"color:#4f5b66;\">;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">async </span><span style=\"color:#b48ead;\">fn </span><span style=\"color:#8fa1b3;\">fetch_cats</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#bf616a;\">count</span><span style=\"color:#4f5b66;\">: CatCount) -&gt; Result&lt;Vec&lt;String&gt;&gt; {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">if</span><span style=\"color:#4f5b66;\"> count &gt; </span><span style=\"color:#d08770;\">0 </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// make the request\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> res = reqwasm::http::Request::get(&amp;format!(\n</span><span style=\"color:#4f5b66;\">            &quot;</span><span style=\"color:#a3be8c;\">https://api.thecatapi.com/v1/images/search?limit=</span><span style=\"color:#d08770;\">{count}</span><span style=\"color:#4f5b66;\">&quot;,\n</span><span style=\"color:#4f5b66;\">        ))\n</span><span style=\"color:#4f5b66;\">        .</span><span style=\"color:#96b5b4;\">send</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">        .await?\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// convert it to JSON\n</span><span style=\"color:#4f5b66;\">        .json::&lt;Vec&lt;Cat&gt;&gt;()\n</span><span style=\"color:#4f5b66;\">        .await?\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// extract the URL field for each cat\n</span><span style=\"color:#4f5b66;\">        .</span><span style=\"color:#96b5b4;\">into_iter</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">        .</span><span style=\"color:#96b5b4;\">take</span><span style=\"color:#4f5b66;\">(count)\n</span><span style=\"color:#4f5b66;\">        .</span><span style=\"color:#96b5b4;\">map</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">cat</span><span style=\"color:#4f5b66;\">| cat.url)\n</span><span style=\"color:#4f5b66;\">        .collect::&lt;Vec&lt;_&gt;&gt;();\n</span><span style=\"color:#4f5b66;\">        Ok(res)\n</span><span style=\"color:#4f5b66;\">    } </span><span style=\"color:#b48ead;\">else </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">        Err(CatError::NonZeroCats.</span><span style=\"color:#96b5b4;\">into</span><span style=\"color:#4f5b66;\">())\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">showcase</span><span style=\"color:#4f5b66;\">() -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let </span><span style=\"color:#4f5b66;\">(cat_count, set_cat_count) = create_signal::&lt;CatCount&gt;(</span><span style=\"color:#d08770;\">0</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// we use local_resource here because\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// 1) our error type isn&#39;t serializable/deserializable\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// 2) we&#39;re not doing server-side rendering in this example anyway\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">//    (during SSR, create_resource will begin loading on the server and resolve on the client)\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> cats = </span><span style=\"color:#96b5b4;\">create_local_resource</span><span style=\"color:#4f5b66;\">(cat_count, fetch_cats);\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> fallback = </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|errors: RwSignal&lt;Errors&gt;| {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> error_list = </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| {\n</span><span style=\"color:#4f5b66;\">            errors.</span><span style=\"color:#96b5b4;\">with</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">errors</span><span style=\"color:#4f5b66;\">| {\n</span><span style=\"color:#4f5b66;\">                errors\n</span><span style=\"color:#4f5b66;\">                    .</span><span style=\"color:#96b5b4;\">iter</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                    .</span><span style=\"color:#96b5b4;\">map</span><span style=\"color:#4f5b66;\">(|(_, </span><span style=\"color:#bf616a;\">e</span><span style=\"color:#4f5b66;\">)| view! { &lt;li&gt;{e.</span><span style=\"color:#96b5b4;\">to_string</span><span style=\"color:#4f5b66;\">()}&lt;/li&gt; })\n</span><span style=\"color:#4f5b66;\">                    .</span><span style=\"color:#96b5b4;\">collect_view</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">            })\n</span><span style=\"color:#4f5b66;\">        };\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">        view! {\n</span><span style=\"color:#4f5b66;\">            &lt;div class=&quot;</span><span style=\"color:#a3be8c;\">error</span><span style=\"color:#4f5b66;\">&quot;&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;h2&gt;&quot;</span><span style=\"color:#a3be8c;\">Error</span><span style=\"color:#4f5b66;\">&quot;&lt;/h2&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;ul&gt;{error_list}&lt;/ul&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;/div&gt;\n</span><span style=\"color:#4f5b66;\">        }\n</span><span style=\"color:#4f5b66;\">    };\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// the renderer can handle Option&lt;_&gt; and Result&lt;_&gt; states\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// by displaying nothing for None if the resource is still loading\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// and by using the ErrorBoundary fallback to catch Err(_)\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// so we&#39;ll just use `.and_then()` to map over the happy path\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> cats_view = </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| {\n</span><span style=\"color:#4f5b66;\">        cats.</span><span style=\"color:#96b5b4;\">and_then</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">data</span><span style=\"color:#4f5b66;\">| {\n</span><span style=\"color:#4f5b66;\">            data.</span><span style=\"color:#96b5b4;\">iter</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">map</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">s</span><span style=\"color:#4f5b66;\">| view! { &lt;p&gt;&lt;img src={s}/&gt;&lt;/p&gt; })\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">collect_view</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">        })\n</span><span style=\"color:#4f5b66;\">    };\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    view! {\n</span><span style=\"color:#4f5b66;\">        &lt;div&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;label&gt;\n</span><span style=\"color:#4f5b66;\">                &quot;</span><span style=\"color:#a3be8c;\">How many cats would you like?</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                &lt;input\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#b48ead;\">type</span><span style=\"color:#4f5b66;\">=&quot;</span><span style=\"color:#a3be8c;\">number</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                    prop:value=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| cat_count.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">to_string</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                    on:input=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|ev| {\n</span><span style=\"color:#4f5b66;\">                        </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> val = </span><span style=\"color:#96b5b4;\">event_target_value</span><span style=\"color:#4f5b66;\">(&amp;ev).parse::&lt;CatCount&gt;().</span><span style=\"color:#96b5b4;\">unwrap_or</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">0</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">                        </span><span style=\"color:#96b5b4;\">set_cat_count</span><span style=\"color:#4f5b66;\">(val);\n</span><span style=\"color:#4f5b66;\">                    }\n</span><span style=\"color:#4f5b66;\">                /&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;/label&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;ErrorBoundary fallback&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;Transition fallback=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| {\n</span><span style=\"color:#4f5b66;\">                    view! { &lt;div&gt;&quot;</span><span style=\"color:#a3be8c;\">Loading (Suspense Fallback)...</span><span style=\"color:#4f5b66;\">&quot;&lt;/div&gt; }\n</span><span style=\"color:#4f5b66;\">                }&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;div&gt;\n</span><span style=\"color:#4f5b66;\">                    {cats_view}\n</span><span style=\"color:#4f5b66;\">                &lt;/div&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;/Transition&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;/ErrorBoundary&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;/div&gt;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span></pre>\n",
-            code: pack_example(fetch::showcase),
-            css: stylist::style!("").unwrap(),
-            description: "This example shows how to fetch data from the client in WebAssembly.\n",
-            motivation: "<p>Mainly illustrate the <code>create_resource</code> function. From the leptos docs:\nA Resource is a reactive data structure that reflects the current state of an asynchronous task, allowing you to integrate asynchronous Futures into the synchronous reactive system. Rather than waiting for its data to load with .await, you transform the Future into a signal that returns Some(T) if it has resolved, and None if it’s still pending.</p>\n",
-            related: Some(
-                "<p><a href=\"#error_boundary\">error_boundary</a>\n<a href=\"https://docs.rs/leptos/latest/leptos/fn.create_resource.html\">https://docs.rs/leptos/latest/leptos/fn.create_resource.html</a>\n<a href=\"https://leptos-rs.github.io/leptos/async/10_resources.html\">https://leptos-rs.github.io/leptos/async/10_resources.html</a></p>\n",
-            ),
-        },
-        Example {
-            name: "html_callback",
-            source: "use leptos::*;\nuse leptos::html::AnyElement;\n\n#[component]\nfn MyFavoriteNumbers(\n    #[prop(into)]\n    render_number: Callback<i32, HtmlElement<AnyElement>>\n    ) -> impl IntoView {\n    view!{\n        // this syntax only works on nightly.\n        // When you are not on nightly, use `render_number.call(...)`\n        I like {render_number(73)}\n        <br/>\n        But I love {render_number(42)}\n    }\n}\n\npub fn showcase() -> impl IntoView {\n    view!{\n        <MyFavoriteNumbers \n            render_number=|x| view!{<b>{x}</b>}\n        />\n    }\n}\n",
-            highlighted_source: "<pre style=\"background-color:#eff1f5;\">\n<span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">leptos::*;\n</span><span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">leptos::html::AnyElement;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">#[</span><span style=\"color:#bf616a;\">component</span><span style=\"color:#4f5b66;\">]\n</span><span style=\"color:#b48ead;\">fn </span><span style=\"color:#8fa1b3;\">MyFavoriteNumbers</span><span style=\"color:#4f5b66;\">(\n</span><span style=\"color:#4f5b66;\">    #[prop(</span><span style=\"color:#bf616a;\">into</span><span style=\"color:#4f5b66;\">)]\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#bf616a;\">render_number</span><span style=\"color:#4f5b66;\">: Callback&lt;</span><span style=\"color:#b48ead;\">i32</span><span style=\"color:#4f5b66;\">, HtmlElement&lt;AnyElement&gt;&gt;\n</span><span style=\"color:#4f5b66;\">    ) -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    view!{\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// this syntax only works on nightly.\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// When you are not on nightly, use `render_number.call(...)`\n</span><span style=\"color:#4f5b66;\">        I like {</span><span style=\"color:#96b5b4;\">render_number</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">73</span><span style=\"color:#4f5b66;\">)}\n</span><span style=\"color:#4f5b66;\">        &lt;br/&gt;\n</span><span style=\"color:#4f5b66;\">        But I love {</span><span style=\"color:#96b5b4;\">render_number</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">42</span><span style=\"color:#4f5b66;\">)}\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">showcase</span><span style=\"color:#4f5b66;\">() -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    view!{\n</span><span style=\"color:#4f5b66;\">        &lt;MyFavoriteNumbers \n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#8fa1b3;\">render_number</span><span style=\"color:#4f5b66;\">=|</span><span style=\"color:#bf616a;\">x</span><span style=\"color:#4f5b66;\">| view!{&lt;b&gt;{x}&lt;/b&gt;}\n</span><span style=\"color:#4f5b66;\">        /&gt;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span></pre>\n",
-            code: pack_example(html_callback::showcase),
-            css: stylist::style!("").unwrap(),
-            description: "pass a function that returns html as a prop",
-            motivation: "<p>Illustrates how you can pass a function that returns html as a prop,\nwith the help of callbacks</p>\n",
-            related: Some(
-                "<p><a href=\"https://docs.rs/leptos/latest/leptos/callback/index.html\">https://docs.rs/leptos/latest/leptos/callback/index.html</a></p>\n",
-            ),
-        },
-        Example {
-            name: "timer",
-            source: "use leptos::{leptos_dom::helpers::IntervalHandle, *};\nuse std::time::Duration;\n\n/// Timer example, demonstrating the use of `use_interval`.\npub fn showcase() -> impl IntoView {\n    // count_a updates with a fixed interval of 1000 ms, whereas count_b has a dynamic\n    // update interval.\n    let (count_a, set_count_a) = create_signal(0_i32);\n    let (count_b, set_count_b) = create_signal(0_i32);\n\n    let (interval, set_interval) = create_signal(1000);\n\n    use_interval(1000, move || {\n        set_count_a.update(|c| *c += 1);\n    });\n    use_interval(interval, move || {\n        set_count_b.update(|c| *c += 1);\n    });\n\n    view! {\n        <div>\n            <div>\"Count A (fixed interval of 1000 ms)\"</div>\n            <div>{count_a}</div>\n            <div>\"Count B (dynamic interval, currently \" {interval} \" ms)\"</div>\n            <div>{count_b}</div>\n            <input prop:value=interval on:input=move |ev| {\n                if let Ok(value) = event_target_value(&ev).parse::<u64>() {\n                    set_interval(value);\n                }\n            }/>\n        </div>\n    }\n}\n\n/// Hook to wrap the underlying `setInterval` call and make it reactive w.r.t.\n/// possible changes of the timer interval.\npub fn use_interval<T, F>(interval_millis: T, f: F)\nwhere\n    F: Fn() + Clone + 'static,\n    T: Into<MaybeSignal<u64>> + 'static,\n{\n    let interval_millis = interval_millis.into();\n    create_effect(move |prev_handle: Option<IntervalHandle>| {\n        // effects get their previous return value as an argument\n        // each time the effect runs, it will return the interval handle\n        // so if we have a previous one, we cancel it\n        if let Some(prev_handle) = prev_handle {\n            prev_handle.clear();\n        };\n\n        // here, we return the handle\n        set_interval_with_handle(\n            f.clone(),\n            // this is the only reactive access, so this effect will only\n            // re-run when the interval changes\n            Duration::from_millis(interval_millis.get()),\n        )\n        .expect(\"could not create interval\")\n    });\n}\n",
-            highlighted_source: "<pre style=\"background-color:#eff1f5;\">\n<span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">leptos::{leptos_dom::helpers::IntervalHandle, *};\n</span><span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">std::time::Duration;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#a7adba;\">/// Timer example, demonstrating the use of `use_interval`.\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">showcase</span><span style=\"color:#4f5b66;\">() -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// count_a updates with a fixed interval of 1000 ms, whereas count_b has a dynamic\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// update interval.\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let </span><span style=\"color:#4f5b66;\">(count_a, set_count_a) = </span><span style=\"color:#96b5b4;\">create_signal</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">0_</span><span style=\"color:#b48ead;\">i32</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let </span><span style=\"color:#4f5b66;\">(count_b, set_count_b) = </span><span style=\"color:#96b5b4;\">create_signal</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">0_</span><span style=\"color:#b48ead;\">i32</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let </span><span style=\"color:#4f5b66;\">(interval, set_interval) = </span><span style=\"color:#96b5b4;\">create_signal</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">1000</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#96b5b4;\">use_interval</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">1000</span><span style=\"color:#4f5b66;\">, </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| {\n</span><span style=\"color:#4f5b66;\">        set_count_a.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">c</span><span style=\"color:#4f5b66;\">| *c += </span><span style=\"color:#d08770;\">1</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">    });\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#96b5b4;\">use_interval</span><span style=\"color:#4f5b66;\">(interval, </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| {\n</span><span style=\"color:#4f5b66;\">        set_count_b.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">c</span><span style=\"color:#4f5b66;\">| *c += </span><span style=\"color:#d08770;\">1</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">    });\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    view! {\n</span><span style=\"color:#4f5b66;\">        &lt;div&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;div&gt;&quot;</span><span style=\"color:#a3be8c;\">Count A (fixed interval of 1000 ms)</span><span style=\"color:#4f5b66;\">&quot;&lt;/div&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;div&gt;{count_a}&lt;/div&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;div&gt;&quot;</span><span style=\"color:#a3be8c;\">Count B (dynamic interval, currently </span><span style=\"color:#4f5b66;\">&quot; {interval} &quot;</span><span style=\"color:#a3be8c;\"> ms)</span><span style=\"color:#4f5b66;\">&quot;&lt;/div&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;div&gt;{count_b}&lt;/div&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;input prop:value=interval on:input=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|ev| {\n</span><span style=\"color:#4f5b66;\">                </span><span style=\"color:#b48ead;\">if let </span><span style=\"color:#4f5b66;\">Ok(value) = </span><span style=\"color:#96b5b4;\">event_target_value</span><span style=\"color:#4f5b66;\">(&amp;ev).parse::&lt;</span><span style=\"color:#b48ead;\">u64</span><span style=\"color:#4f5b66;\">&gt;() {\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#96b5b4;\">set_interval</span><span style=\"color:#4f5b66;\">(value);\n</span><span style=\"color:#4f5b66;\">                }\n</span><span style=\"color:#4f5b66;\">            }/&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;/div&gt;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#a7adba;\">/// Hook to wrap the underlying `setInterval` call and make it reactive w.r.t.\n</span><span style=\"color:#a7adba;\">/// possible changes of the timer interval.\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">use_interval</span><span style=\"color:#4f5b66;\">&lt;T, F&gt;(</span><span style=\"color:#bf616a;\">interval_millis</span><span style=\"color:#4f5b66;\">: T, </span><span style=\"color:#bf616a;\">f</span><span style=\"color:#4f5b66;\">: F)\n</span><span style=\"color:#b48ead;\">where\n</span><span style=\"color:#4f5b66;\">    F: Fn() + Clone + </span><span style=\"color:#b48ead;\">&#39;static</span><span style=\"color:#4f5b66;\">,\n</span><span style=\"color:#4f5b66;\">    T: Into&lt;MaybeSignal&lt;</span><span style=\"color:#b48ead;\">u64</span><span style=\"color:#4f5b66;\">&gt;&gt; + </span><span style=\"color:#b48ead;\">&#39;static</span><span style=\"color:#4f5b66;\">,\n</span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> interval_millis = interval_millis.</span><span style=\"color:#96b5b4;\">into</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#96b5b4;\">create_effect</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|prev_handle: Option&lt;IntervalHandle&gt;| {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// effects get their previous return value as an argument\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// each time the effect runs, it will return the interval handle\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// so if we have a previous one, we cancel it\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">if let </span><span style=\"color:#4f5b66;\">Some(prev_handle) = prev_handle {\n</span><span style=\"color:#4f5b66;\">            prev_handle.</span><span style=\"color:#96b5b4;\">clear</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">        };\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// here, we return the handle\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#96b5b4;\">set_interval_with_handle</span><span style=\"color:#4f5b66;\">(\n</span><span style=\"color:#4f5b66;\">            f.</span><span style=\"color:#96b5b4;\">clone</span><span style=\"color:#4f5b66;\">(),\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#a7adba;\">// this is the only reactive access, so this effect will only\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#a7adba;\">// re-run when the interval changes\n</span><span style=\"color:#4f5b66;\">            Duration::from_millis(interval_millis.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">()),\n</span><span style=\"color:#4f5b66;\">        )\n</span><span style=\"color:#4f5b66;\">        .</span><span style=\"color:#96b5b4;\">expect</span><span style=\"color:#4f5b66;\">(&quot;</span><span style=\"color:#a3be8c;\">could not create interval</span><span style=\"color:#4f5b66;\">&quot;)\n</span><span style=\"color:#4f5b66;\">    });\n</span><span style=\"color:#4f5b66;\">}\n</span></pre>\n",
-            code: pack_example(timer::showcase),
-            css: stylist::style!("").unwrap(),
-            description: "simple timer based on setInterval",
-            motivation: "<p>This example illustrates how you can add timing to your app, the same way as in javascript</p>\n",
-            related: Some(
-                "<p><a href=\"https://developer.mozilla.org/en-US/docs/Web/API/setInterval\">https://developer.mozilla.org/en-US/docs/Web/API/setInterval</a></p>\n",
-            ),
-        },
-        Example {
-            name: "maybe_signal",
-            source: "use leptos::*;\n\n#[component]\nfn Greeter(\n    #[prop(into)]\n    name: MaybeSignal<String>\n    ) -> impl IntoView {\n\n    view!{\n        <p>\n            hello {move || name()} !\n        </p>\n    }\n}\n\npub fn showcase() -> impl IntoView {\n    let (changing_name, set_name) = create_signal(\"bob\".to_string());\n\n    view!{\n        <h3>This name will never change</h3>\n        <Greeter name=\"rust\"/>\n\n        <h3>This name can change</h3>\n        <div>\n            <button on:click=move |_| set_name(\"alice\".to_string())>\n                alice\n            </button>\n            <button on:click=move |_| set_name(\"bob\".to_string())>\n                bob\n            </button>\n        </div>\n        <Greeter name=changing_name/>\n    }\n}\n",
-            highlighted_source: "<pre style=\"background-color:#eff1f5;\">\n<span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">leptos::*;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">#[</span><span style=\"color:#bf616a;\">component</span><span style=\"color:#4f5b66;\">]\n</span><span style=\"color:#b48ead;\">fn </span><span style=\"color:#8fa1b3;\">Greeter</span><span style=\"color:#4f5b66;\">(\n</span><span style=\"color:#4f5b66;\">    #[prop(</span><span style=\"color:#bf616a;\">into</span><span style=\"color:#4f5b66;\">)]\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#bf616a;\">name</span><span style=\"color:#4f5b66;\">: MaybeSignal&lt;String&gt;\n</span><span style=\"color:#4f5b66;\">    ) -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    view!{\n</span><span style=\"color:#4f5b66;\">        &lt;p&gt;\n</span><span style=\"color:#4f5b66;\">            hello {</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| </span><span style=\"color:#96b5b4;\">name</span><span style=\"color:#4f5b66;\">()} !\n</span><span style=\"color:#4f5b66;\">        &lt;/p&gt;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">showcase</span><span style=\"color:#4f5b66;\">() -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let </span><span style=\"color:#4f5b66;\">(changing_name, set_name) = </span><span style=\"color:#96b5b4;\">create_signal</span><span style=\"color:#4f5b66;\">(&quot;</span><span style=\"color:#a3be8c;\">bob</span><span style=\"color:#4f5b66;\">&quot;.</span><span style=\"color:#96b5b4;\">to_string</span><span style=\"color:#4f5b66;\">());\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    view!{\n</span><span style=\"color:#4f5b66;\">        &lt;h3&gt;This name will never change&lt;</span><span style=\"background-color:#bf616a;color:#eff1f5;\">/</span><span style=\"color:#4f5b66;\">h3&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;Greeter name=&quot;</span><span style=\"color:#a3be8c;\">rust</span><span style=\"color:#4f5b66;\">&quot;/&gt;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">        &lt;h3&gt;This name can change&lt;</span><span style=\"background-color:#bf616a;color:#eff1f5;\">/</span><span style=\"color:#4f5b66;\">h3&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;div&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;button on:click=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| </span><span style=\"color:#96b5b4;\">set_name</span><span style=\"color:#4f5b66;\">(&quot;</span><span style=\"color:#a3be8c;\">alice</span><span style=\"color:#4f5b66;\">&quot;.</span><span style=\"color:#96b5b4;\">to_string</span><span style=\"color:#4f5b66;\">())&gt;\n</span><span style=\"color:#4f5b66;\">                alice\n</span><span style=\"color:#4f5b66;\">            &lt;/button&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;button on:click=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| </span><span style=\"color:#96b5b4;\">set_name</span><span style=\"color:#4f5b66;\">(&quot;</span><span style=\"color:#a3be8c;\">bob</span><span style=\"color:#4f5b66;\">&quot;.</span><span style=\"color:#96b5b4;\">to_string</span><span style=\"color:#4f5b66;\">())&gt;\n</span><span style=\"color:#4f5b66;\">                bob\n</span><span style=\"color:#4f5b66;\">            &lt;/button&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;/div&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;Greeter name=changing_name/&gt;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span></pre>\n",
-            code: pack_example(maybe_signal::showcase),
-            css: stylist::style!("").unwrap(),
-            description: "pass either a dynamic or a static property",
-            motivation: "<p>illustrates how to use <code>MaybeSignal</code> to pass either a dynamic, either a static prop</p>\n",
-            related: Some(
-                "<p><a href=\"https://docs.rs/leptos/latest/leptos/enum.MaybeSignal.html\">https://docs.rs/leptos/latest/leptos/enum.MaybeSignal.html</a></p>\n",
-            ),
-        },
-        Example {
-            name: "todomvc",
-            source: "use leptos::{html::Input, leptos_dom::helpers::location_hash, *};\nuse leptos::logging::error;\nuse serde::{Deserialize, Serialize};\nuse uuid::Uuid;\n\n#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]\npub struct Todos(pub Vec<Todo>);\n\nconst STORAGE_KEY: &str = \"todos-leptos\";\n\n// Basic operations to manipulate the todo list: nothing really interesting here\nimpl Todos {\n    pub fn new() -> Self {\n        let starting_todos =\n            window()\n                .local_storage()\n                .ok()\n                .flatten()\n                .and_then(|storage| {\n                    storage.get_item(STORAGE_KEY).ok().flatten().and_then(\n                        |value| serde_json::from_str::<Vec<Todo>>(&value).ok(),\n                    )\n                })\n                .unwrap_or_default();\n        Self(starting_todos)\n    }\n\n    pub fn is_empty(&self) -> bool {\n        self.0.is_empty()\n    }\n\n    pub fn add(&mut self, todo: Todo) {\n        self.0.push(todo);\n    }\n\n    pub fn remove(&mut self, id: Uuid) {\n        self.retain(|todo| todo.id != id);\n    }\n\n    pub fn remaining(&self) -> usize {\n        // `todo.completed` is a signal, so we call .get() to access its value\n        self.0.iter().filter(|todo| !todo.completed.get()).count()\n    }\n\n    pub fn completed(&self) -> usize {\n        // `todo.completed` is a signal, so we call .get() to access its value\n        self.0.iter().filter(|todo| todo.completed.get()).count()\n    }\n\n    pub fn toggle_all(&self) {\n        // if all are complete, mark them all active\n        if self.remaining() == 0 {\n            for todo in &self.0 {\n                todo.completed.update(|completed| {\n                    if *completed {\n                        *completed = false\n                    }\n                });\n            }\n        }\n        // otherwise, mark them all complete\n        else {\n            for todo in &self.0 {\n                todo.completed.set(true);\n            }\n        }\n    }\n\n    fn clear_completed(&mut self) {\n        self.retain(|todo| !todo.completed.get());\n    }\n\n    fn retain(&mut self, mut f: impl FnMut(&Todo) -> bool) {\n        self.0.retain(|todo| {\n            let retain = f(todo);\n            // because these signals are created at the top level,\n            // they are owned by the <TodoMVC/> component and not\n            // by the individual <Todo/> components. This means\n            // that if they are not manually disposed when removed, they\n            // will be held onto until the <TodoMVC/> is unmounted.\n            if !retain {\n                todo.title.dispose();\n                todo.completed.dispose();\n            }\n            retain\n        })\n    }\n}\n\nimpl Default for Todos {\n    fn default() -> Self {\n        Self::new()\n    }\n}\n\n#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]\npub struct Todo {\n    pub id: Uuid,\n    pub title: RwSignal<String>,\n    pub completed: RwSignal<bool>,\n}\n\nimpl Todo {\n    pub fn new(id: Uuid, title: String) -> Self {\n        Self::new_with_completed(id, title, false)\n    }\n\n    pub fn new_with_completed(\n        id: Uuid,\n        title: String,\n        completed: bool,\n    ) -> Self {\n        // RwSignal combines the getter and setter in one struct, rather than separating\n        // the getter from the setter. This makes it more convenient in some cases, such\n        // as when we're putting the signals into a struct and passing it around. There's\n        // no real difference: you could use `create_signal` here, or use `create_rw_signal`\n        // everywhere.\n        let title = create_rw_signal(title);\n        let completed = create_rw_signal(completed);\n        Self {\n            id,\n            title,\n            completed,\n        }\n    }\n\n    pub fn toggle(&self) {\n        // A signal's `update()` function gives you a mutable reference to the current value\n        // You can use that to modify the value in place, which will notify any subscribers.\n        self.completed.update(|completed| *completed = !*completed);\n    }\n}\n\nconst ESCAPE_KEY: u32 = 27;\nconst ENTER_KEY: u32 = 13;\n\npub fn showcase() -> impl IntoView {\n    // The `todos` are a signal, since we need to reactively update the list\n    let (todos, set_todos) = create_signal(Todos::new());\n\n    // We provide a context that each <Todo/> component can use to update the list\n    // Here, I'm just passing the `WriteSignal`; a <Todo/> doesn't need to read the whole list\n    // (and shouldn't try to, as that would cause each individual <Todo/> to re-render when\n    // a new todo is added! This kind of hygiene is why `create_signal` defaults to read-write\n    // segregation.)\n    provide_context(set_todos);\n\n    // Handle the three filter modes: All, Active, and Completed\n    let (mode, set_mode) = create_signal(Mode::All);\n    window_event_listener(ev::hashchange, move |_| {\n        let new_mode =\n            location_hash().map(|hash| route(&hash)).unwrap_or_default();\n        set_mode(new_mode);\n    });\n\n    // Callback to add a todo on pressing the `Enter` key, if the field isn't empty\n    let input_ref = create_node_ref::<Input>();\n    let add_todo = move |ev: web_sys::KeyboardEvent| {\n        let input = input_ref.get().unwrap();\n        ev.stop_propagation();\n        let key_code = ev.key_code();\n        if key_code == ENTER_KEY {\n            let title = input.value();\n            let title = title.trim();\n            if !title.is_empty() {\n                let new = Todo::new(Uuid::new_v4(), title.to_string());\n                set_todos.update(|t| t.add(new));\n                input.set_value(\"\");\n            }\n        }\n    };\n\n    // A derived signal that filters the list of the todos depending on the filter mode\n    // This doesn't need to be a `Memo`, because we're only reading it in one place\n    let filtered_todos = move || {\n        todos.with(|todos| match mode.get() {\n            Mode::All => todos.0.to_vec(),\n            Mode::Active => todos\n                .0\n                .iter()\n                .filter(|todo| !todo.completed.get())\n                .cloned()\n                .collect(),\n            Mode::Completed => todos\n                .0\n                .iter()\n                .filter(|todo| todo.completed.get())\n                .cloned()\n                .collect(),\n        })\n    };\n\n    // Serialization\n    //\n    // the effect reads the `todos` signal, and each `Todo`'s title and completed\n    // status,  so it will automatically re-run on any change to the list of tasks\n    //\n    // this is the main point of `create_effect`: to synchronize reactive state\n    // with something outside the reactive system (like localStorage)\n    create_effect(move |_| {\n        if let Ok(Some(storage)) = window().local_storage() {\n            let json = serde_json::to_string(&todos)\n                .expect(\"couldn't serialize Todos\");\n            if storage.set_item(STORAGE_KEY, &json).is_err() {\n                error!(\"error while trying to set item in localStorage\");\n            }\n        }\n    });\n\n    // focus the main input on load\n    create_effect(move |_| {\n        if let Some(input) = input_ref.get() {\n            let _ = input.focus();\n        }\n    });\n\n    view! {\n        <main>\n            <section class=\"todoapp\">\n                <header class=\"header\">\n                    <h1>\"todos\"</h1>\n                    <input\n                        class=\"new-todo\"\n                        placeholder=\"What needs to be done?\"\n                        autofocus\n                        on:keydown=add_todo\n                        node_ref=input_ref\n                    />\n                </header>\n                <section\n                    class=\"main\"\n                    class:hidden={move || todos.with(|t| t.is_empty())}\n                >\n                    <input id=\"toggle-all\" class=\"toggle-all\" type=\"checkbox\"\n                        prop:checked={move || todos.with(|t| t.remaining() > 0)}\n                        on:input=move |_| todos.with(|t| t.toggle_all())\n                    />\n                    <label for=\"toggle-all\">\"Mark all as complete\"</label>\n                    <ul class=\"todo-list\">\n                        <For\n                            each=filtered_todos\n                            key=|todo| todo.id\n                            let:todo\n                        >\n                            <Todo todo/>\n                        </For>\n                    </ul>\n                </section>\n                <footer\n                    class=\"footer\"\n                    class:hidden={move || todos.with(|t| t.is_empty())}\n                >\n                    <span class=\"todo-count\">\n                        <strong>{move || todos.with(|t| t.remaining().to_string())}</strong>\n                        {move || if todos.with(|t| t.remaining()) == 1 {\n                            \" item\"\n                        } else {\n                            \" items\"\n                        }}\n                        \" left\"\n                    </span>\n                    <ul class=\"filters\">\n                        <li><a href=\"#/\" class=\"selected\" class:selected={move || mode() == Mode::All}>\"All\"</a></li>\n                        <li><a href=\"#/active\" class:selected={move || mode() == Mode::Active}>\"Active\"</a></li>\n                        <li><a href=\"#/completed\" class:selected={move || mode() == Mode::Completed}>\"Completed\"</a></li>\n                    </ul>\n                    <button\n                        class=\"clear-completed hidden\"\n                        class:hidden={move || todos.with(|t| t.completed() == 0)}\n                        on:click=move |_| set_todos.update(|t| t.clear_completed())\n                    >\n                        \"Clear completed\"\n                    </button>\n                </footer>\n            </section>\n            <footer class=\"info\">\n                <p>\"Double-click to edit a todo\"</p>\n                <p>\"Created by \"<a href=\"http://todomvc.com\">\"Greg Johnston\"</a></p>\n                <p>\"Part of \"<a href=\"http://todomvc.com\">\"TodoMVC\"</a></p>\n            </footer>\n        </main>\n    }\n}\n\n#[component]\npub fn Todo(todo: Todo) -> impl IntoView {\n    let (editing, set_editing) = create_signal(false);\n    let set_todos = use_context::<WriteSignal<Todos>>().unwrap();\n\n    // this will be filled by node_ref=input below\n    let todo_input = create_node_ref::<Input>();\n\n    let save = move |value: &str| {\n        let value = value.trim();\n        if value.is_empty() {\n            set_todos.update(|t| t.remove(todo.id));\n        } else {\n            todo.title.set(value.to_string());\n        }\n        set_editing(false);\n    };\n\n    view! {\n        <li\n            class=\"todo\"\n            class:editing={editing}\n            class:completed={move || todo.completed.get()}\n        >\n            <div class=\"view\">\n                <input\n                    node_ref=todo_input\n                    class=\"toggle\"\n                    type=\"checkbox\"\n                    prop:checked={move || (todo.completed)()}\n                    on:input={move |ev| {\n                        let checked = event_target_checked(&ev);\n                        todo.completed.set(checked);\n                    }}\n                />\n                <label on:dblclick=move |_| {\n                    set_editing(true);\n\n                    if let Some(input) = todo_input.get() {\n                        _ = input.focus();\n                    }\n                }>\n                    {move || todo.title.get()}\n                </label>\n                <button class=\"destroy\" on:click=move |_| set_todos.update(|t| t.remove(todo.id))/>\n            </div>\n            {move || editing().then(|| view! {\n                <input\n                    class=\"edit\"\n                    class:hidden={move || !(editing)()}\n                    prop:value={move || todo.title.get()}\n                    on:focusout=move |ev: web_sys::FocusEvent| save(&event_target_value(&ev))\n                    on:keyup={move |ev: web_sys::KeyboardEvent| {\n                        let key_code = ev.key_code();\n                        if key_code == ENTER_KEY {\n                            save(&event_target_value(&ev));\n                        } else if key_code == ESCAPE_KEY {\n                            set_editing(false);\n                        }\n                    }}\n                />\n            })\n        }\n        </li>\n    }\n}\n\n#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]\npub enum Mode {\n    Active,\n    Completed,\n    #[default]\n    All,\n}\n\nfn route(hash: &str) -> Mode {\n    match hash {\n        \"/active\" => Mode::Active,\n        \"/completed\" => Mode::Completed,\n        _ => Mode::All,\n    }\n}\n",
-            highlighted_source: "<pre style=\"background-color:#eff1f5;\">\n<span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">leptos::{html::Input, leptos_dom::helpers::location_hash, *};\n</span><span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">leptos::logging::error;\n</span><span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">serde::{Deserialize, Serialize};\n</span><span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">uuid::Uuid;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">#[</span><span style=\"color:#bf616a;\">derive</span><span style=\"color:#4f5b66;\">(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]\n</span><span style=\"color:#b48ead;\">pub struct </span><span style=\"color:#4f5b66;\">Todos(pub Vec&lt;Todo&gt;);\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">const </span><span style=\"color:#d08770;\">STORAGE_KEY</span><span style=\"color:#4f5b66;\">: &amp;</span><span style=\"color:#b48ead;\">str </span><span style=\"color:#4f5b66;\">= &quot;</span><span style=\"color:#a3be8c;\">todos-leptos</span><span style=\"color:#4f5b66;\">&quot;;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#a7adba;\">// Basic operations to manipulate the todo list: nothing really interesting here\n</span><span style=\"color:#b48ead;\">impl </span><span style=\"color:#4f5b66;\">Todos {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">new</span><span style=\"color:#4f5b66;\">() -&gt; </span><span style=\"color:#b48ead;\">Self </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> starting_todos =\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#96b5b4;\">window</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">local_storage</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">ok</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">flatten</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">and_then</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">storage</span><span style=\"color:#4f5b66;\">| {\n</span><span style=\"color:#4f5b66;\">                    storage.</span><span style=\"color:#96b5b4;\">get_item</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">STORAGE_KEY</span><span style=\"color:#4f5b66;\">).</span><span style=\"color:#96b5b4;\">ok</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">flatten</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">and_then</span><span style=\"color:#4f5b66;\">(\n</span><span style=\"color:#4f5b66;\">                        |</span><span style=\"color:#bf616a;\">value</span><span style=\"color:#4f5b66;\">| serde_json::from_str::&lt;Vec&lt;Todo&gt;&gt;(&amp;value).</span><span style=\"color:#96b5b4;\">ok</span><span style=\"color:#4f5b66;\">(),\n</span><span style=\"color:#4f5b66;\">                    )\n</span><span style=\"color:#4f5b66;\">                })\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">unwrap_or_default</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">Self</span><span style=\"color:#4f5b66;\">(starting_todos)\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">is_empty</span><span style=\"color:#4f5b66;\">(&amp;</span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">) -&gt; </span><span style=\"color:#b48ead;\">bool </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.</span><span style=\"color:#d08770;\">0.</span><span style=\"color:#96b5b4;\">is_empty</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">add</span><span style=\"color:#4f5b66;\">(&amp;</span><span style=\"color:#b48ead;\">mut </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">, </span><span style=\"color:#bf616a;\">todo</span><span style=\"color:#4f5b66;\">: Todo) {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.</span><span style=\"color:#d08770;\">0.</span><span style=\"color:#96b5b4;\">push</span><span style=\"color:#4f5b66;\">(todo);\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">remove</span><span style=\"color:#4f5b66;\">(&amp;</span><span style=\"color:#b48ead;\">mut </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">, </span><span style=\"color:#bf616a;\">id</span><span style=\"color:#4f5b66;\">: Uuid) {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.</span><span style=\"color:#96b5b4;\">retain</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">todo</span><span style=\"color:#4f5b66;\">| todo.id != id);\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">remaining</span><span style=\"color:#4f5b66;\">(&amp;</span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">) -&gt; </span><span style=\"color:#b48ead;\">usize </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// `todo.completed` is a signal, so we call .get() to access its value\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.</span><span style=\"color:#d08770;\">0.</span><span style=\"color:#96b5b4;\">iter</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">filter</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">todo</span><span style=\"color:#4f5b66;\">| !todo.completed.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">()).</span><span style=\"color:#96b5b4;\">count</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">completed</span><span style=\"color:#4f5b66;\">(&amp;</span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">) -&gt; </span><span style=\"color:#b48ead;\">usize </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// `todo.completed` is a signal, so we call .get() to access its value\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.</span><span style=\"color:#d08770;\">0.</span><span style=\"color:#96b5b4;\">iter</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">filter</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">todo</span><span style=\"color:#4f5b66;\">| todo.completed.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">()).</span><span style=\"color:#96b5b4;\">count</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">toggle_all</span><span style=\"color:#4f5b66;\">(&amp;</span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">) {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// if all are complete, mark them all active\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">if </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.</span><span style=\"color:#96b5b4;\">remaining</span><span style=\"color:#4f5b66;\">() == </span><span style=\"color:#d08770;\">0 </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#b48ead;\">for</span><span style=\"color:#4f5b66;\"> todo in &amp;</span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.</span><span style=\"color:#d08770;\">0 </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">                todo.completed.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">completed</span><span style=\"color:#4f5b66;\">| {\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#b48ead;\">if </span><span style=\"color:#4f5b66;\">*completed {\n</span><span style=\"color:#4f5b66;\">                        *completed = </span><span style=\"color:#d08770;\">false\n</span><span style=\"color:#4f5b66;\">                    }\n</span><span style=\"color:#4f5b66;\">                });\n</span><span style=\"color:#4f5b66;\">            }\n</span><span style=\"color:#4f5b66;\">        }\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// otherwise, mark them all complete\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">else </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#b48ead;\">for</span><span style=\"color:#4f5b66;\"> todo in &amp;</span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.</span><span style=\"color:#d08770;\">0 </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">                todo.completed.</span><span style=\"color:#96b5b4;\">set</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">true</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">            }\n</span><span style=\"color:#4f5b66;\">        }\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">fn </span><span style=\"color:#8fa1b3;\">clear_completed</span><span style=\"color:#4f5b66;\">(&amp;</span><span style=\"color:#b48ead;\">mut </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">) {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.</span><span style=\"color:#96b5b4;\">retain</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">todo</span><span style=\"color:#4f5b66;\">| !todo.completed.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">());\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">fn </span><span style=\"color:#8fa1b3;\">retain</span><span style=\"color:#4f5b66;\">(&amp;</span><span style=\"color:#b48ead;\">mut </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">, </span><span style=\"color:#b48ead;\">mut </span><span style=\"color:#bf616a;\">f</span><span style=\"color:#4f5b66;\">: impl FnMut(&amp;</span><span style=\"color:#bf616a;\">Todo</span><span style=\"color:#4f5b66;\">) -&gt; </span><span style=\"color:#b48ead;\">bool</span><span style=\"color:#4f5b66;\">) {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.</span><span style=\"color:#d08770;\">0.</span><span style=\"color:#96b5b4;\">retain</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">todo</span><span style=\"color:#4f5b66;\">| {\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> retain = </span><span style=\"color:#96b5b4;\">f</span><span style=\"color:#4f5b66;\">(todo);\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#a7adba;\">// because these signals are created at the top level,\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#a7adba;\">// they are owned by the &lt;TodoMVC/&gt; component and not\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#a7adba;\">// by the individual &lt;Todo/&gt; components. This means\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#a7adba;\">// that if they are not manually disposed when removed, they\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#a7adba;\">// will be held onto until the &lt;TodoMVC/&gt; is unmounted.\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#b48ead;\">if </span><span style=\"color:#4f5b66;\">!retain {\n</span><span style=\"color:#4f5b66;\">                todo.title.</span><span style=\"color:#96b5b4;\">dispose</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">                todo.completed.</span><span style=\"color:#96b5b4;\">dispose</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">            }\n</span><span style=\"color:#4f5b66;\">            retain\n</span><span style=\"color:#4f5b66;\">        })\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">impl </span><span style=\"color:#4f5b66;\">Default </span><span style=\"color:#b48ead;\">for </span><span style=\"color:#4f5b66;\">Todos {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">fn </span><span style=\"color:#8fa1b3;\">default</span><span style=\"color:#4f5b66;\">() -&gt; </span><span style=\"color:#b48ead;\">Self </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">Self</span><span style=\"color:#4f5b66;\">::new()\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">#[</span><span style=\"color:#bf616a;\">derive</span><span style=\"color:#4f5b66;\">(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]\n</span><span style=\"color:#b48ead;\">pub struct </span><span style=\"color:#4f5b66;\">Todo {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub </span><span style=\"color:#bf616a;\">id</span><span style=\"color:#4f5b66;\">: Uuid,\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub </span><span style=\"color:#bf616a;\">title</span><span style=\"color:#4f5b66;\">: RwSignal&lt;String&gt;,\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub </span><span style=\"color:#bf616a;\">completed</span><span style=\"color:#4f5b66;\">: RwSignal&lt;</span><span style=\"color:#b48ead;\">bool</span><span style=\"color:#4f5b66;\">&gt;,\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">impl </span><span style=\"color:#4f5b66;\">Todo {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">new</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#bf616a;\">id</span><span style=\"color:#4f5b66;\">: Uuid, </span><span style=\"color:#bf616a;\">title</span><span style=\"color:#4f5b66;\">: String) -&gt; </span><span style=\"color:#b48ead;\">Self </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">Self</span><span style=\"color:#4f5b66;\">::new_with_completed(id, title, </span><span style=\"color:#d08770;\">false</span><span style=\"color:#4f5b66;\">)\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">new_with_completed</span><span style=\"color:#4f5b66;\">(\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#bf616a;\">id</span><span style=\"color:#4f5b66;\">: Uuid,\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#bf616a;\">title</span><span style=\"color:#4f5b66;\">: String,\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#bf616a;\">completed</span><span style=\"color:#4f5b66;\">: </span><span style=\"color:#b48ead;\">bool</span><span style=\"color:#4f5b66;\">,\n</span><span style=\"color:#4f5b66;\">    ) -&gt; </span><span style=\"color:#b48ead;\">Self </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// RwSignal combines the getter and setter in one struct, rather than separating\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// the getter from the setter. This makes it more convenient in some cases, such\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// as when we&#39;re putting the signals into a struct and passing it around. There&#39;s\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// no real difference: you could use `create_signal` here, or use `create_rw_signal`\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// everywhere.\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> title = </span><span style=\"color:#96b5b4;\">create_rw_signal</span><span style=\"color:#4f5b66;\">(title);\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> completed = </span><span style=\"color:#96b5b4;\">create_rw_signal</span><span style=\"color:#4f5b66;\">(completed);\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">Self </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">            id,\n</span><span style=\"color:#4f5b66;\">            title,\n</span><span style=\"color:#4f5b66;\">            completed,\n</span><span style=\"color:#4f5b66;\">        }\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">toggle</span><span style=\"color:#4f5b66;\">(&amp;</span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">) {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// A signal&#39;s `update()` function gives you a mutable reference to the current value\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// You can use that to modify the value in place, which will notify any subscribers.\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#bf616a;\">self</span><span style=\"color:#4f5b66;\">.completed.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">completed</span><span style=\"color:#4f5b66;\">| *completed = !*completed);\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">const </span><span style=\"color:#d08770;\">ESCAPE_KEY</span><span style=\"color:#4f5b66;\">: </span><span style=\"color:#b48ead;\">u32 </span><span style=\"color:#4f5b66;\">= </span><span style=\"color:#d08770;\">27</span><span style=\"color:#4f5b66;\">;\n</span><span style=\"color:#b48ead;\">const </span><span style=\"color:#d08770;\">ENTER_KEY</span><span style=\"color:#4f5b66;\">: </span><span style=\"color:#b48ead;\">u32 </span><span style=\"color:#4f5b66;\">= </span><span style=\"color:#d08770;\">13</span><span style=\"color:#4f5b66;\">;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">showcase</span><span style=\"color:#4f5b66;\">() -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// The `todos` are a signal, since we need to reactively update the list\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let </span><span style=\"color:#4f5b66;\">(todos, set_todos) = </span><span style=\"color:#96b5b4;\">create_signal</span><span style=\"color:#4f5b66;\">(Todos::new());\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// We provide a context that each &lt;Todo/&gt; component can use to update the list\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// Here, I&#39;m just passing the `WriteSignal`; a &lt;Todo/&gt; doesn&#39;t need to read the whole list\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// (and shouldn&#39;t try to, as that would cause each individual &lt;Todo/&gt; to re-render when\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// a new todo is added! This kind of hygiene is why `create_signal` defaults to read-write\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// segregation.)\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#96b5b4;\">provide_context</span><span style=\"color:#4f5b66;\">(set_todos);\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// Handle the three filter modes: All, Active, and Completed\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let </span><span style=\"color:#4f5b66;\">(mode, set_mode) = </span><span style=\"color:#96b5b4;\">create_signal</span><span style=\"color:#4f5b66;\">(Mode::All);\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#96b5b4;\">window_event_listener</span><span style=\"color:#4f5b66;\">(ev::hashchange, </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> new_mode =\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#96b5b4;\">location_hash</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">map</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">hash</span><span style=\"color:#4f5b66;\">| </span><span style=\"color:#96b5b4;\">route</span><span style=\"color:#4f5b66;\">(&amp;hash)).</span><span style=\"color:#96b5b4;\">unwrap_or_default</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#96b5b4;\">set_mode</span><span style=\"color:#4f5b66;\">(new_mode);\n</span><span style=\"color:#4f5b66;\">    });\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// Callback to add a todo on pressing the `Enter` key, if the field isn&#39;t empty\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> input_ref = create_node_ref::&lt;Input&gt;();\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> add_todo = </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|ev: web_sys::KeyboardEvent| {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> input = input_ref.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">unwrap</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">        ev.</span><span style=\"color:#96b5b4;\">stop_propagation</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> key_code = ev.</span><span style=\"color:#96b5b4;\">key_code</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">if</span><span style=\"color:#4f5b66;\"> key_code == </span><span style=\"color:#d08770;\">ENTER_KEY </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> title = input.</span><span style=\"color:#96b5b4;\">value</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> title = title.</span><span style=\"color:#96b5b4;\">trim</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#b48ead;\">if </span><span style=\"color:#4f5b66;\">!title.</span><span style=\"color:#96b5b4;\">is_empty</span><span style=\"color:#4f5b66;\">() {\n</span><span style=\"color:#4f5b66;\">                </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> new = Todo::new(Uuid::new_v4(), title.</span><span style=\"color:#96b5b4;\">to_string</span><span style=\"color:#4f5b66;\">());\n</span><span style=\"color:#4f5b66;\">                set_todos.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">t</span><span style=\"color:#4f5b66;\">| t.</span><span style=\"color:#96b5b4;\">add</span><span style=\"color:#4f5b66;\">(new));\n</span><span style=\"color:#4f5b66;\">                input.</span><span style=\"color:#96b5b4;\">set_value</span><span style=\"color:#4f5b66;\">(&quot;&quot;);\n</span><span style=\"color:#4f5b66;\">            }\n</span><span style=\"color:#4f5b66;\">        }\n</span><span style=\"color:#4f5b66;\">    };\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// A derived signal that filters the list of the todos depending on the filter mode\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// This doesn&#39;t need to be a `Memo`, because we&#39;re only reading it in one place\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> filtered_todos = </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| {\n</span><span style=\"color:#4f5b66;\">        todos.</span><span style=\"color:#96b5b4;\">with</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">todos</span><span style=\"color:#4f5b66;\">| </span><span style=\"color:#b48ead;\">match</span><span style=\"color:#4f5b66;\"> mode.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">() {\n</span><span style=\"color:#4f5b66;\">            Mode::All =&gt; todos.</span><span style=\"color:#d08770;\">0.</span><span style=\"color:#96b5b4;\">to_vec</span><span style=\"color:#4f5b66;\">(),\n</span><span style=\"color:#4f5b66;\">            Mode::Active =&gt; todos\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#d08770;\">0\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">iter</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">filter</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">todo</span><span style=\"color:#4f5b66;\">| !todo.completed.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">())\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">cloned</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">collect</span><span style=\"color:#4f5b66;\">(),\n</span><span style=\"color:#4f5b66;\">            Mode::Completed =&gt; todos\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#d08770;\">0\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">iter</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">filter</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">todo</span><span style=\"color:#4f5b66;\">| todo.completed.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">())\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">cloned</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">collect</span><span style=\"color:#4f5b66;\">(),\n</span><span style=\"color:#4f5b66;\">        })\n</span><span style=\"color:#4f5b66;\">    };\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// Serialization\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">//\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// the effect reads the `todos` signal, and each `Todo`&#39;s title and completed\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// status,  so it will automatically re-run on any change to the list of tasks\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">//\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// this is the main point of `create_effect`: to synchronize reactive state\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// with something outside the reactive system (like localStorage)\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#96b5b4;\">create_effect</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">if let </span><span style=\"color:#4f5b66;\">Ok(Some(storage)) = </span><span style=\"color:#96b5b4;\">window</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">local_storage</span><span style=\"color:#4f5b66;\">() {\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> json = serde_json::to_string(&amp;todos)\n</span><span style=\"color:#4f5b66;\">                .</span><span style=\"color:#96b5b4;\">expect</span><span style=\"color:#4f5b66;\">(&quot;</span><span style=\"color:#a3be8c;\">couldn&#39;t serialize Todos</span><span style=\"color:#4f5b66;\">&quot;);\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#b48ead;\">if</span><span style=\"color:#4f5b66;\"> storage.</span><span style=\"color:#96b5b4;\">set_item</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">STORAGE_KEY</span><span style=\"color:#4f5b66;\">, &amp;json).</span><span style=\"color:#96b5b4;\">is_err</span><span style=\"color:#4f5b66;\">() {\n</span><span style=\"color:#4f5b66;\">                error!(&quot;</span><span style=\"color:#a3be8c;\">error while trying to set item in localStorage</span><span style=\"color:#4f5b66;\">&quot;);\n</span><span style=\"color:#4f5b66;\">            }\n</span><span style=\"color:#4f5b66;\">        }\n</span><span style=\"color:#4f5b66;\">    });\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// focus the main input on load\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#96b5b4;\">create_effect</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">if let </span><span style=\"color:#4f5b66;\">Some(input) = input_ref.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">() {\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#b48ead;\">let </span><span style=\"color:#4f5b66;\">_ = input.</span><span style=\"color:#96b5b4;\">focus</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">        }\n</span><span style=\"color:#4f5b66;\">    });\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    view! {\n</span><span style=\"color:#4f5b66;\">        &lt;main&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;section class=&quot;</span><span style=\"color:#a3be8c;\">todoapp</span><span style=\"color:#4f5b66;\">&quot;&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;header class=&quot;</span><span style=\"color:#a3be8c;\">header</span><span style=\"color:#4f5b66;\">&quot;&gt;\n</span><span style=\"color:#4f5b66;\">                    &lt;h1&gt;&quot;</span><span style=\"color:#a3be8c;\">todos</span><span style=\"color:#4f5b66;\">&quot;&lt;/h1&gt;\n</span><span style=\"color:#4f5b66;\">                    &lt;input\n</span><span style=\"color:#4f5b66;\">                        class=&quot;</span><span style=\"color:#a3be8c;\">new-todo</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                        placeholder=&quot;</span><span style=\"color:#a3be8c;\">What needs to be done?</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                        autofocus\n</span><span style=\"color:#4f5b66;\">                        on:keydown=add_todo\n</span><span style=\"color:#4f5b66;\">                        node_ref=input_ref\n</span><span style=\"color:#4f5b66;\">                    /&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;/header&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;section\n</span><span style=\"color:#4f5b66;\">                    class=&quot;</span><span style=\"color:#a3be8c;\">main</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                    class:hidden={</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| todos.</span><span style=\"color:#96b5b4;\">with</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">t</span><span style=\"color:#4f5b66;\">| t.</span><span style=\"color:#96b5b4;\">is_empty</span><span style=\"color:#4f5b66;\">())}\n</span><span style=\"color:#4f5b66;\">                &gt;\n</span><span style=\"color:#4f5b66;\">                    &lt;input id=&quot;</span><span style=\"color:#a3be8c;\">toggle-all</span><span style=\"color:#4f5b66;\">&quot; class=&quot;</span><span style=\"color:#a3be8c;\">toggle-all</span><span style=\"color:#4f5b66;\">&quot; </span><span style=\"color:#b48ead;\">type</span><span style=\"color:#4f5b66;\">=&quot;</span><span style=\"color:#a3be8c;\">checkbox</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                        prop:checked={</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| todos.</span><span style=\"color:#96b5b4;\">with</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">t</span><span style=\"color:#4f5b66;\">| t.</span><span style=\"color:#96b5b4;\">remaining</span><span style=\"color:#4f5b66;\">() &gt; </span><span style=\"color:#d08770;\">0</span><span style=\"color:#4f5b66;\">)}\n</span><span style=\"color:#4f5b66;\">                        on:input=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| todos.</span><span style=\"color:#96b5b4;\">with</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">t</span><span style=\"color:#4f5b66;\">| t.</span><span style=\"color:#96b5b4;\">toggle_all</span><span style=\"color:#4f5b66;\">())\n</span><span style=\"color:#4f5b66;\">                    /&gt;\n</span><span style=\"color:#4f5b66;\">                    &lt;label </span><span style=\"color:#b48ead;\">for</span><span style=\"color:#4f5b66;\">=&quot;</span><span style=\"color:#a3be8c;\">toggle-all</span><span style=\"color:#4f5b66;\">&quot;&gt;&quot;</span><span style=\"color:#a3be8c;\">Mark all as complete</span><span style=\"color:#4f5b66;\">&quot;&lt;/label&gt;\n</span><span style=\"color:#4f5b66;\">                    &lt;ul class=&quot;</span><span style=\"color:#a3be8c;\">todo-list</span><span style=\"color:#4f5b66;\">&quot;&gt;\n</span><span style=\"color:#4f5b66;\">                        &lt;For\n</span><span style=\"color:#4f5b66;\">                            each=filtered_todos\n</span><span style=\"color:#4f5b66;\">                            </span><span style=\"color:#8fa1b3;\">key</span><span style=\"color:#4f5b66;\">=|</span><span style=\"color:#bf616a;\">todo</span><span style=\"color:#4f5b66;\">| todo.id\n</span><span style=\"color:#4f5b66;\">                            </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\">:todo\n</span><span style=\"color:#4f5b66;\">                        &gt;\n</span><span style=\"color:#4f5b66;\">                            &lt;Todo todo/&gt;\n</span><span style=\"color:#4f5b66;\">                        &lt;/For&gt;\n</span><span style=\"color:#4f5b66;\">                    &lt;/ul&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;/section&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;footer\n</span><span style=\"color:#4f5b66;\">                    class=&quot;</span><span style=\"color:#a3be8c;\">footer</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                    class:hidden={</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| todos.</span><span style=\"color:#96b5b4;\">with</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">t</span><span style=\"color:#4f5b66;\">| t.</span><span style=\"color:#96b5b4;\">is_empty</span><span style=\"color:#4f5b66;\">())}\n</span><span style=\"color:#4f5b66;\">                &gt;\n</span><span style=\"color:#4f5b66;\">                    &lt;span class=&quot;</span><span style=\"color:#a3be8c;\">todo-count</span><span style=\"color:#4f5b66;\">&quot;&gt;\n</span><span style=\"color:#4f5b66;\">                        &lt;strong&gt;{</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| todos.</span><span style=\"color:#96b5b4;\">with</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">t</span><span style=\"color:#4f5b66;\">| t.</span><span style=\"color:#96b5b4;\">remaining</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">to_string</span><span style=\"color:#4f5b66;\">())}&lt;/strong&gt;\n</span><span style=\"color:#4f5b66;\">                        {</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| </span><span style=\"color:#b48ead;\">if</span><span style=\"color:#4f5b66;\"> todos.</span><span style=\"color:#96b5b4;\">with</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">t</span><span style=\"color:#4f5b66;\">| t.</span><span style=\"color:#96b5b4;\">remaining</span><span style=\"color:#4f5b66;\">()) == </span><span style=\"color:#d08770;\">1 </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">                            &quot;</span><span style=\"color:#a3be8c;\"> item</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                        } </span><span style=\"color:#b48ead;\">else </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">                            &quot;</span><span style=\"color:#a3be8c;\"> items</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                        }}\n</span><span style=\"color:#4f5b66;\">                        &quot;</span><span style=\"color:#a3be8c;\"> left</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                    &lt;/span&gt;\n</span><span style=\"color:#4f5b66;\">                    &lt;ul class=&quot;</span><span style=\"color:#a3be8c;\">filters</span><span style=\"color:#4f5b66;\">&quot;&gt;\n</span><span style=\"color:#4f5b66;\">                        &lt;li&gt;&lt;a href=&quot;</span><span style=\"color:#a3be8c;\">#/</span><span style=\"color:#4f5b66;\">&quot; class=&quot;</span><span style=\"color:#a3be8c;\">selected</span><span style=\"color:#4f5b66;\">&quot; class:selected={</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| </span><span style=\"color:#96b5b4;\">mode</span><span style=\"color:#4f5b66;\">() == Mode::All}&gt;&quot;</span><span style=\"color:#a3be8c;\">All</span><span style=\"color:#4f5b66;\">&quot;&lt;/a&gt;&lt;/li&gt;\n</span><span style=\"color:#4f5b66;\">                        &lt;li&gt;&lt;a href=&quot;</span><span style=\"color:#a3be8c;\">#/active</span><span style=\"color:#4f5b66;\">&quot; class:selected={</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| </span><span style=\"color:#96b5b4;\">mode</span><span style=\"color:#4f5b66;\">() == Mode::Active}&gt;&quot;</span><span style=\"color:#a3be8c;\">Active</span><span style=\"color:#4f5b66;\">&quot;&lt;/a&gt;&lt;/li&gt;\n</span><span style=\"color:#4f5b66;\">                        &lt;li&gt;&lt;a href=&quot;</span><span style=\"color:#a3be8c;\">#/completed</span><span style=\"color:#4f5b66;\">&quot; class:selected={</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| </span><span style=\"color:#96b5b4;\">mode</span><span style=\"color:#4f5b66;\">() == Mode::Completed}&gt;&quot;</span><span style=\"color:#a3be8c;\">Completed</span><span style=\"color:#4f5b66;\">&quot;&lt;/a&gt;&lt;/li&gt;\n</span><span style=\"color:#4f5b66;\">                    &lt;/ul&gt;\n</span><span style=\"color:#4f5b66;\">                    &lt;button\n</span><span style=\"color:#4f5b66;\">                        class=&quot;</span><span style=\"color:#a3be8c;\">clear-completed hidden</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                        class:hidden={</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| todos.</span><span style=\"color:#96b5b4;\">with</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">t</span><span style=\"color:#4f5b66;\">| t.</span><span style=\"color:#96b5b4;\">completed</span><span style=\"color:#4f5b66;\">() == </span><span style=\"color:#d08770;\">0</span><span style=\"color:#4f5b66;\">)}\n</span><span style=\"color:#4f5b66;\">                        on:click=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| set_todos.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">t</span><span style=\"color:#4f5b66;\">| t.</span><span style=\"color:#96b5b4;\">clear_completed</span><span style=\"color:#4f5b66;\">())\n</span><span style=\"color:#4f5b66;\">                    &gt;\n</span><span style=\"color:#4f5b66;\">                        &quot;</span><span style=\"color:#a3be8c;\">Clear completed</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                    &lt;/button&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;/footer&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;/section&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;footer class=&quot;</span><span style=\"color:#a3be8c;\">info</span><span style=\"color:#4f5b66;\">&quot;&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;p&gt;&quot;</span><span style=\"color:#a3be8c;\">Double-click to edit a todo</span><span style=\"color:#4f5b66;\">&quot;&lt;/p&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;p&gt;&quot;</span><span style=\"color:#a3be8c;\">Created by </span><span style=\"color:#4f5b66;\">&quot;&lt;a href=&quot;</span><span style=\"color:#a3be8c;\">http://todomvc.com</span><span style=\"color:#4f5b66;\">&quot;&gt;&quot;</span><span style=\"color:#a3be8c;\">Greg Johnston</span><span style=\"color:#4f5b66;\">&quot;&lt;/a&gt;&lt;/p&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;p&gt;&quot;</span><span style=\"color:#a3be8c;\">Part of </span><span style=\"color:#4f5b66;\">&quot;&lt;a href=&quot;</span><span style=\"color:#a3be8c;\">http://todomvc.com</span><span style=\"color:#4f5b66;\">&quot;&gt;&quot;</span><span style=\"color:#a3be8c;\">TodoMVC</span><span style=\"color:#4f5b66;\">&quot;&lt;/a&gt;&lt;/p&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;/footer&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;/main&gt;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">#[</span><span style=\"color:#bf616a;\">component</span><span style=\"color:#4f5b66;\">]\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">Todo</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#bf616a;\">todo</span><span style=\"color:#4f5b66;\">: Todo) -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let </span><span style=\"color:#4f5b66;\">(editing, set_editing) = </span><span style=\"color:#96b5b4;\">create_signal</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">false</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> set_todos = use_context::&lt;WriteSignal&lt;Todos&gt;&gt;().</span><span style=\"color:#96b5b4;\">unwrap</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// this will be filled by node_ref=input below\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> todo_input = create_node_ref::&lt;Input&gt;();\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> save = </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|value: &amp;</span><span style=\"color:#b48ead;\">str</span><span style=\"color:#4f5b66;\">| {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> value = value.</span><span style=\"color:#96b5b4;\">trim</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">if</span><span style=\"color:#4f5b66;\"> value.</span><span style=\"color:#96b5b4;\">is_empty</span><span style=\"color:#4f5b66;\">() {\n</span><span style=\"color:#4f5b66;\">            set_todos.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">t</span><span style=\"color:#4f5b66;\">| t.</span><span style=\"color:#96b5b4;\">remove</span><span style=\"color:#4f5b66;\">(todo.id));\n</span><span style=\"color:#4f5b66;\">        } </span><span style=\"color:#b48ead;\">else </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">            todo.title.</span><span style=\"color:#96b5b4;\">set</span><span style=\"color:#4f5b66;\">(value.</span><span style=\"color:#96b5b4;\">to_string</span><span style=\"color:#4f5b66;\">());\n</span><span style=\"color:#4f5b66;\">        }\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#96b5b4;\">set_editing</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">false</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">    };\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    view! {\n</span><span style=\"color:#4f5b66;\">        &lt;li\n</span><span style=\"color:#4f5b66;\">            class=&quot;</span><span style=\"color:#a3be8c;\">todo</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">            class:editing={editing}\n</span><span style=\"color:#4f5b66;\">            class:completed={</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| todo.completed.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">()}\n</span><span style=\"color:#4f5b66;\">        &gt;\n</span><span style=\"color:#4f5b66;\">            &lt;div class=&quot;</span><span style=\"color:#a3be8c;\">view</span><span style=\"color:#4f5b66;\">&quot;&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;input\n</span><span style=\"color:#4f5b66;\">                    node_ref=todo_input\n</span><span style=\"color:#4f5b66;\">                    class=&quot;</span><span style=\"color:#a3be8c;\">toggle</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#b48ead;\">type</span><span style=\"color:#4f5b66;\">=&quot;</span><span style=\"color:#a3be8c;\">checkbox</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                    prop:checked={</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| (todo.completed)()}\n</span><span style=\"color:#4f5b66;\">                    on:input={</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|ev| {\n</span><span style=\"color:#4f5b66;\">                        </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> checked = </span><span style=\"color:#96b5b4;\">event_target_checked</span><span style=\"color:#4f5b66;\">(&amp;ev);\n</span><span style=\"color:#4f5b66;\">                        todo.completed.</span><span style=\"color:#96b5b4;\">set</span><span style=\"color:#4f5b66;\">(checked);\n</span><span style=\"color:#4f5b66;\">                    }}\n</span><span style=\"color:#4f5b66;\">                /&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;label on:dblclick=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| {\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#96b5b4;\">set_editing</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">true</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#b48ead;\">if let </span><span style=\"color:#4f5b66;\">Some(input) = todo_input.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">() {\n</span><span style=\"color:#4f5b66;\">                        _ = input.</span><span style=\"color:#96b5b4;\">focus</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">                    }\n</span><span style=\"color:#4f5b66;\">                }&gt;\n</span><span style=\"color:#4f5b66;\">                    {</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| todo.title.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">()}\n</span><span style=\"color:#4f5b66;\">                &lt;/label&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;button class=&quot;</span><span style=\"color:#a3be8c;\">destroy</span><span style=\"color:#4f5b66;\">&quot; on:click=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| set_todos.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">t</span><span style=\"color:#4f5b66;\">| t.</span><span style=\"color:#96b5b4;\">remove</span><span style=\"color:#4f5b66;\">(todo.id))/&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;/div&gt;\n</span><span style=\"color:#4f5b66;\">            {</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| </span><span style=\"color:#96b5b4;\">editing</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">then</span><span style=\"color:#4f5b66;\">(|| view! {\n</span><span style=\"color:#4f5b66;\">                &lt;input\n</span><span style=\"color:#4f5b66;\">                    class=&quot;</span><span style=\"color:#a3be8c;\">edit</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                    class:hidden={</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| !(editing)()}\n</span><span style=\"color:#4f5b66;\">                    prop:value={</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| todo.title.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">()}\n</span><span style=\"color:#4f5b66;\">                    on:focusout=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|ev: web_sys::FocusEvent| </span><span style=\"color:#96b5b4;\">save</span><span style=\"color:#4f5b66;\">(&amp;</span><span style=\"color:#96b5b4;\">event_target_value</span><span style=\"color:#4f5b66;\">(&amp;ev))\n</span><span style=\"color:#4f5b66;\">                    on:keyup={</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|ev: web_sys::KeyboardEvent| {\n</span><span style=\"color:#4f5b66;\">                        </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> key_code = ev.</span><span style=\"color:#96b5b4;\">key_code</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">                        </span><span style=\"color:#b48ead;\">if</span><span style=\"color:#4f5b66;\"> key_code == </span><span style=\"color:#d08770;\">ENTER_KEY </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">                            </span><span style=\"color:#96b5b4;\">save</span><span style=\"color:#4f5b66;\">(&amp;</span><span style=\"color:#96b5b4;\">event_target_value</span><span style=\"color:#4f5b66;\">(&amp;ev));\n</span><span style=\"color:#4f5b66;\">                        } </span><span style=\"color:#b48ead;\">else if</span><span style=\"color:#4f5b66;\"> key_code == </span><span style=\"color:#d08770;\">ESCAPE_KEY </span><span style=\"color:#4f5b66;\">{\n</span><span style=\"color:#4f5b66;\">                            </span><span style=\"color:#96b5b4;\">set_editing</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">false</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">                        }\n</span><span style=\"color:#4f5b66;\">                    }}\n</span><span style=\"color:#4f5b66;\">                /&gt;\n</span><span style=\"color:#4f5b66;\">            })\n</span><span style=\"color:#4f5b66;\">        }\n</span><span style=\"color:#4f5b66;\">        &lt;/li&gt;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">#[</span><span style=\"color:#bf616a;\">derive</span><span style=\"color:#4f5b66;\">(Debug, Default, Clone, Copy, PartialEq, Eq)]\n</span><span style=\"color:#b48ead;\">pub enum </span><span style=\"color:#4f5b66;\">Mode {\n</span><span style=\"color:#4f5b66;\">    Active,\n</span><span style=\"color:#4f5b66;\">    Completed,\n</span><span style=\"color:#4f5b66;\">    #[</span><span style=\"color:#bf616a;\">default</span><span style=\"color:#4f5b66;\">]\n</span><span style=\"color:#4f5b66;\">    All,\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">fn </span><span style=\"color:#8fa1b3;\">route</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#bf616a;\">hash</span><span style=\"color:#4f5b66;\">: &amp;</span><span style=\"color:#b48ead;\">str</span><span style=\"color:#4f5b66;\">) -&gt; Mode {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">match</span><span style=\"color:#4f5b66;\"> hash {\n</span><span style=\"color:#4f5b66;\">        &quot;</span><span style=\"color:#a3be8c;\">/active</span><span style=\"color:#4f5b66;\">&quot; =&gt; Mode::Active,\n</span><span style=\"color:#4f5b66;\">        &quot;</span><span style=\"color:#a3be8c;\">/completed</span><span style=\"color:#4f5b66;\">&quot; =&gt; Mode::Completed,\n</span><span style=\"color:#4f5b66;\">        _ =&gt; Mode::All,\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span></pre>\n",
-            code: pack_example(todomvc::showcase),
-            css: stylist::style!(
-                " hr {\n\tmargin: 20px 0;\n\tborder: 0;\n\tborder-top: 1px dashed #c5c5c5;\n\tborder-bottom: 1px dashed #f7f7f7;\n}\n\n .learn a {\n\tfont-weight: normal;\n\ttext-decoration: none;\n\tcolor: #b83f45;\n}\n\n .learn a:hover {\n\ttext-decoration: underline;\n\tcolor: #787e7e;\n}\n\n .learn h3,\n.learn h4,\n.learn h5 {\n\tmargin: 10px 0;\n\tfont-weight: 500;\n\tline-height: 1.2;\n\tcolor: #000;\n}\n\n .learn h3 {\n\tfont-size: 24px;\n}\n\n .learn h4 {\n\tfont-size: 18px;\n}\n\n .learn h5 {\n\tmargin-bottom: 0;\n\tfont-size: 14px;\n}\n\n .learn ul {\n\tpadding: 0;\n\tmargin: 0 0 30px 25px;\n}\n\n .learn li {\n\tline-height: 20px;\n}\n\n .learn p {\n\tfont-size: 15px;\n\tfont-weight: 300;\n\tline-height: 1.3;\n\tmargin-top: 0;\n\tmargin-bottom: 0;\n}\n\n #issue-count {\n\tdisplay: none;\n}\n\n .quote {\n\tborder: none;\n\tmargin: 20px 0 60px 0;\n}\n\n .quote p {\n\tfont-style: italic;\n}\n\n .quote p:before {\n\tcontent: '“';\n\tfont-size: 50px;\n\topacity: .15;\n\tposition: absolute;\n\ttop: -20px;\n\tleft: 3px;\n}\n\n .quote p:after {\n\tcontent: '”';\n\tfont-size: 50px;\n\topacity: .15;\n\tposition: absolute;\n\tbottom: -42px;\n\tright: 3px;\n}\n\n .quote footer {\n\tposition: absolute;\n\tbottom: -40px;\n\tright: 0;\n}\n\n .quote footer img {\n\tborder-radius: 3px;\n}\n\n .quote footer a {\n\tmargin-left: 5px;\n\tvertical-align: middle;\n}\n\n .speech-bubble {\n\tposition: relative;\n\tpadding: 10px;\n\tbackground: rgba(0, 0, 0, .04);\n\tborder-radius: 5px;\n}\n\n .speech-bubble:after {\n\tcontent: '';\n\tposition: absolute;\n\ttop: 100%;\n\tright: 30px;\n\tborder: 13px solid transparent;\n\tborder-top-color: rgba(0, 0, 0, .04);\n}\n\n .learn-bar > .learn {\n\tposition: absolute;\n\twidth: 272px;\n\ttop: 8px;\n\tleft: -300px;\n\tpadding: 10px;\n\tborder-radius: 5px;\n\tbackground-color: rgba(255, 255, 255, .6);\n\ttransition-property: left;\n\ttransition-duration: 500ms;\n}\n\n@media (min-width: 899px) {\n\t .learn-bar {\n\t\twidth: auto;\n\t\tpadding-left: 300px;\n\t}\n\n\t .learn-bar > .learn {\n\t\tleft: 8px;\n\t}\n}\n\n button {\n\tmargin: 0;\n\tpadding: 0;\n\tborder: 0;\n\tbackground: none;\n\tfont-size: 100%;\n\tvertical-align: baseline;\n\tfont-family: inherit;\n\tfont-weight: inherit;\n\tcolor: inherit;\n\t-webkit-appearance: none;\n\tappearance: none;\n\t-webkit-font-smoothing: antialiased;\n\t-moz-osx-font-smoothing: grayscale;\n}\n\n body {\n\tfont: 14px 'Helvetica Neue', Helvetica, Arial, sans-serif;\n\tline-height: 1.4em;\n\tbackground: #f5f5f5;\n\tcolor: #111111;\n\tmin-width: 230px;\n\tmax-width: 550px;\n\tmargin: 0 auto;\n\t-webkit-font-smoothing: antialiased;\n\t-moz-osx-font-smoothing: grayscale;\n\tfont-weight: 300;\n}\n\n .hidden {\n\tdisplay: none;\n}\n\n .todoapp {\n\tbackground: #fff;\n\tmargin: 130px 0 40px 0;\n\tposition: relative;\n\tbox-shadow: 0 2px 4px 0 rgba(0, 0, 0, 0.2),\n\t            0 25px 50px 0 rgba(0, 0, 0, 0.1);\n}\n\n .todoapp input::-webkit-input-placeholder {\n\tfont-style: italic;\n\tfont-weight: 400;\n\tcolor: rgba(0, 0, 0, 0.4);\n}\n\n .todoapp input::-moz-placeholder {\n\tfont-style: italic;\n\tfont-weight: 400;\n\tcolor: rgba(0, 0, 0, 0.4);\n}\n\n .todoapp input::input-placeholder {\n\tfont-style: italic;\n\tfont-weight: 400;\n\tcolor: rgba(0, 0, 0, 0.4);\n}\n\n .todoapp h1 {\n\tposition: absolute;\n\ttop: -140px;\n\twidth: 100%;\n\tfont-size: 80px;\n\tfont-weight: 200;\n\ttext-align: center;\n\tcolor: #b83f45;\n\t-webkit-text-rendering: optimizeLegibility;\n\t-moz-text-rendering: optimizeLegibility;\n\ttext-rendering: optimizeLegibility;\n}\n\n .new-todo,\n.edit {\n\tposition: relative;\n\tmargin: 0;\n\twidth: 100%;\n\tfont-size: 24px;\n\tfont-family: inherit;\n\tfont-weight: inherit;\n\tline-height: 1.4em;\n\tcolor: inherit;\n\tpadding: 6px;\n\tborder: 1px solid #999;\n\tbox-shadow: inset 0 -1px 5px 0 rgba(0, 0, 0, 0.2);\n\tbox-sizing: border-box;\n\t-webkit-font-smoothing: antialiased;\n\t-moz-osx-font-smoothing: grayscale;\n}\n\n .new-todo {\n\tpadding: 16px 16px 16px 60px;\n\theight: 65px;\n\tborder: none;\n\tbackground: rgba(0, 0, 0, 0.003);\n\tbox-shadow: inset 0 -2px 1px rgba(0,0,0,0.03);\n}\n\n .main {\n\tposition: relative;\n\tz-index: 2;\n\tborder-top: 1px solid #e6e6e6;\n}\n\n .toggle-all {\n\twidth: 1px;\n\theight: 1px;\n\tborder: none; /* Mobile Safari */\n\topacity: 0;\n\tposition: absolute;\n\tright: 100%;\n\tbottom: 100%;\n}\n\n .toggle-all + label {\n\tdisplay: flex;\n\talign-items: center;\n\tjustify-content: center;\n\twidth: 45px;\n\theight: 65px;\n\tfont-size: 0;\n\tposition: absolute;\n\ttop: -65px;\n\tleft: -0;\n}\n\n .toggle-all + label:before {\n\tcontent: '❯';\n\tdisplay: inline-block;\n\tfont-size: 22px;\n\tcolor: #949494;\n\tpadding: 10px 27px 10px 27px;\n\t-webkit-transform: rotate(90deg);\n\ttransform: rotate(90deg);\n}\n\n .toggle-all:checked + label:before {\n\tcolor: #484848;\n}\n\n .todo-list {\n\tmargin: 0;\n\tpadding: 0;\n\tlist-style: none;\n}\n\n .todo-list li {\n\tposition: relative;\n\tfont-size: 24px;\n\tborder-bottom: 1px solid #ededed;\n}\n\n .todo-list li:last-child {\n\tborder-bottom: none;\n}\n\n .todo-list li.editing {\n\tborder-bottom: none;\n\tpadding: 0;\n}\n\n .todo-list li.editing .edit {\n\tdisplay: block;\n\twidth: calc(100% - 43px);\n\tpadding: 12px 16px;\n\tmargin: 0 0 0 43px;\n}\n\n .todo-list li.editing .view {\n\tdisplay: none;\n}\n\n .todo-list li .toggle {\n\ttext-align: center;\n\twidth: 40px;\n\t/* auto, since non-WebKit browsers doesn't support input styling */\n\theight: auto;\n\tposition: absolute;\n\ttop: 0;\n\tbottom: 0;\n\tmargin: auto 0;\n\tborder: none; /* Mobile Safari */\n\t-webkit-appearance: none;\n\tappearance: none;\n}\n\n .todo-list li .toggle {\n\topacity: 0;\n}\n\n\n .todo-list li label {\n\tword-break: break-all;\n\tpadding: 15px 15px 15px 60px;\n\tdisplay: block;\n\tline-height: 1.2;\n\ttransition: color 0.4s;\n\tfont-weight: 400;\n\tcolor: #484848;\n}\n\n .todo-list li.completed label {\n\tcolor: #949494;\n\ttext-decoration: line-through;\n}\n\n .todo-list li .destroy {\n\tdisplay: none;\n\tposition: absolute;\n\ttop: 0;\n\tright: 10px;\n\tbottom: 0;\n\twidth: 40px;\n\theight: 40px;\n\tmargin: auto 0;\n\tfont-size: 30px;\n\tcolor: #949494;\n\ttransition: color 0.2s ease-out;\n}\n\n .todo-list li .destroy:hover,\n .todo-list li .destroy:focus {\n\tcolor: #C18585;\n}\n\n .todo-list li .destroy:after {\n\tcontent: '×';\n\tdisplay: block;\n\theight: 100%;\n\tline-height: 1.1;\n}\n\n .todo-list li:hover .destroy {\n\tdisplay: block;\n}\n\n .todo-list li .edit {\n\tdisplay: none;\n}\n\n .todo-list li.editing:last-child {\n\tmargin-bottom: -1px;\n}\n\n .footer {\n\tpadding: 10px 15px;\n\theight: 20px;\n\ttext-align: center;\n\tfont-size: 15px;\n\tborder-top: 1px solid #e6e6e6;\n}\n\n .footer:before {\n\tcontent: '';\n\tposition: absolute;\n\tright: 0;\n\tbottom: 0;\n\tleft: 0;\n\theight: 50px;\n\toverflow: hidden;\n\tbox-shadow: 0 1px 1px rgba(0, 0, 0, 0.2),\n\t            0 8px 0 -3px #f6f6f6,\n\t            0 9px 1px -3px rgba(0, 0, 0, 0.2),\n\t            0 16px 0 -6px #f6f6f6,\n\t            0 17px 2px -6px rgba(0, 0, 0, 0.2);\n}\n\n .todo-count {\n\tfloat: left;\n\ttext-align: left;\n}\n\n .todo-count strong {\n\tfont-weight: 300;\n}\n\n .filters {\n\tmargin: 0;\n\tpadding: 0;\n\tlist-style: none;\n\tposition: absolute;\n\tright: 0;\n\tleft: 0;\n}\n\n .filters li {\n\tdisplay: inline;\n}\n\n .filters li a {\n\tcolor: inherit;\n\tmargin: 3px;\n\tpadding: 3px 7px;\n\ttext-decoration: none;\n\tborder: 1px solid transparent;\n\tborder-radius: 3px;\n}\n\n .filters li a:hover {\n\tborder-color: #DB7676;\n}\n\n .filters li a.selected {\n\tborder-color: #CE4646;\n}\n\n .clear-completed,\nhtml .clear-completed:active {\n\tfloat: right;\n\tposition: relative;\n\tline-height: 19px;\n\ttext-decoration: none;\n\tcursor: pointer;\n}\n\n .clear-completed:hover {\n\ttext-decoration: underline;\n}\n\n .info {\n\tmargin: 65px auto 0;\n\tcolor: #4d4d4d;\n\tfont-size: 11px;\n\ttext-shadow: 0 1px 0 rgba(255, 255, 255, 0.5);\n\ttext-align: center;\n}\n\n .info p {\n\tline-height: 1;\n}\n\n .info a {\n\tcolor: inherit;\n\ttext-decoration: none;\n\tfont-weight: 400;\n}\n\n .info a:hover {\n\ttext-decoration: underline;\n}\n\n/*\n\tHack to remove background from Mobile Safari.\n\tCan't use it globally since it destroys checkboxes in Firefox\n*/\n@media screen and (-webkit-min-device-pixel-ratio:0) {\n\t .toggle-all,\n\t.todo-list li .toggle {\n\t\tbackground: none;\n\t}\n\n\t .todo-list li .toggle {\n\t\theight: 40px;\n\t}\n}\n\n@media (max-width: 430px) {\n\t .footer {\n\t\theight: 50px;\n\t}\n\n\t .filters {\n\t\tbottom: 10px;\n\t}\n}\n\n :focus,\n.toggle:focus + label,\n.toggle-all:focus + label {\n\tbox-shadow: 0 0 2px 2px #CF7D7D;\n\toutline: 0;\n}\n"
-            )
-                .unwrap(),
-            description: "the classic `todomvc` example, with local storage included",
-            motivation: "<p>It is very well explained at <a href=\"https://todomvc.com/\">https://todomvc.com/</a></p>\n",
-            related: None,
-        },
-        Example {
-            name: "iteration",
-            source: "use leptos::*;\n\n// Iteration is a very common task in most applications.\n// So how do you take a list of data and render it in the DOM?\n// This example will show you the two ways:\n// 1) for mostly-static lists, using Rust iterators\n// 2) for lists that grow, shrink, or move items, using <For/>\n\npub fn showcase() -> impl IntoView {\n    view! {\n        <h1>\"Iteration\"</h1>\n        <h2>\"Static List\"</h2>\n        <p>\"Use this pattern if the list itself is static.\"</p>\n        <StaticList length=5/>\n        <h2>\"Dynamic List\"</h2>\n        <p>\"Use this pattern if the rows in your list will change.\"</p>\n        <DynamicList initial_length=5/>\n    }\n}\n\n/// A list of counters, without the ability\n/// to add or remove any.\n#[component]\nfn StaticList(\n    /// How many counters to include in this list.\n    length: usize,\n) -> impl IntoView {\n    // create counter signals that start at incrementing numbers\n    let counters = (1..=length).map(|idx| create_signal(idx));\n\n    // when you have a list that doesn't change, you can\n    // manipulate it using ordinary Rust iterators\n    // and collect it into a Vec<_> to insert it into the DOM\n    let counter_buttons = counters\n        .map(|(count, set_count)| {\n            view! {\n                <li>\n                    <button\n                        on:click=move |_| set_count.update(|n| *n += 1)\n                    >\n                        {count}\n                    </button>\n                </li>\n            }\n        })\n        .collect::<Vec<_>>();\n\n    // Note that if `counter_buttons` were a reactive list\n    // and its value changed, this would be very inefficient:\n    // it would rerender every row every time the list changed.\n    view! {\n        <ul>{counter_buttons}</ul>\n    }\n}\n\n/// A list of counters that allows you to add or\n/// remove counters.\n#[component]\nfn DynamicList(\n    /// The number of counters to begin with.\n    initial_length: usize,\n) -> impl IntoView {\n    // This dynamic list will use the <For/> component.\n    // <For/> is a keyed list. This means that each row\n    // has a defined key. If the key does not change, the row\n    // will not be re-rendered. When the list changes, only\n    // the minimum number of changes will be made to the DOM.\n\n    // `next_counter_id` will let us generate unique IDs\n    // we do this by simply incrementing the ID by one\n    // each time we create a counter\n    let mut next_counter_id = initial_length;\n\n    // we generate an initial list as in <StaticList/>\n    // but this time we include the ID along with the signal\n    let initial_counters = (0..initial_length)\n        .map(|id| (id, create_signal(id + 1)))\n        .collect::<Vec<_>>();\n\n    // now we store that initial list in a signal\n    // this way, we'll be able to modify the list over time,\n    // adding and removing counters, and it will change reactively\n    let (counters, set_counters) = create_signal(initial_counters);\n\n    let add_counter = move |_| {\n        // create a signal for the new counter\n        let sig = create_signal(next_counter_id + 1);\n        // add this counter to the list of counters\n        set_counters.update(move |counters| {\n            // since `.update()` gives us `&mut T`\n            // we can just use normal Vec methods like `push`\n            counters.push((next_counter_id, sig))\n        });\n        // increment the ID so it's always unique\n        next_counter_id += 1;\n    };\n\n    view! {\n        <div>\n            <button on:click=add_counter>\n                \"Add Counter\"\n            </button>\n            <ul>\n                // The <For/> component is central here\n                // This allows for efficient, key list rendering\n                <For\n                    // `each` takes any function that returns an iterator\n                    // this should usually be a signal or derived signal\n                    // if it's not reactive, just render a Vec<_> instead of <For/>\n                    each=counters\n                    // the key should be unique and stable for each row\n                    // using an index is usually a bad idea, unless your list\n                    // can only grow, because moving items around inside the list\n                    // means their indices will change and they will all rerender\n                    key=|counter| counter.0\n                    // `children` receives each item from your `each` iterator\n                    // and returns a view\n                    children=move |(id, (count, set_count))| {\n                        view! {\n                            <li>\n                                <button\n                                    on:click=move |_| set_count.update(|n| *n += 1)\n                                >\n                                    {count}\n                                </button>\n                                <button\n                                    on:click=move |_| {\n                                        set_counters.update(|counters| {\n                                            counters.retain(|(counter_id, _)| counter_id != &id)\n                                        });\n                                    }\n                                >\n                                    \"Remove\"\n                                </button>\n                            </li>\n                        }\n                    }\n                />\n            </ul>\n        </div>\n    }\n}\n",
-            highlighted_source: "<pre style=\"background-color:#eff1f5;\">\n<span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">leptos::*;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#a7adba;\">// Iteration is a very common task in most applications.\n</span><span style=\"color:#a7adba;\">// So how do you take a list of data and render it in the DOM?\n</span><span style=\"color:#a7adba;\">// This example will show you the two ways:\n</span><span style=\"color:#a7adba;\">// 1) for mostly-static lists, using Rust iterators\n</span><span style=\"color:#a7adba;\">// 2) for lists that grow, shrink, or move items, using &lt;For/&gt;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">showcase</span><span style=\"color:#4f5b66;\">() -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    view! {\n</span><span style=\"color:#4f5b66;\">        &lt;h1&gt;&quot;</span><span style=\"color:#a3be8c;\">Iteration</span><span style=\"color:#4f5b66;\">&quot;&lt;/h1&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;h2&gt;&quot;</span><span style=\"color:#a3be8c;\">Static List</span><span style=\"color:#4f5b66;\">&quot;&lt;/h2&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;p&gt;&quot;</span><span style=\"color:#a3be8c;\">Use this pattern if the list itself is static.</span><span style=\"color:#4f5b66;\">&quot;&lt;/p&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;StaticList length=</span><span style=\"color:#d08770;\">5</span><span style=\"color:#4f5b66;\">/&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;h2&gt;&quot;</span><span style=\"color:#a3be8c;\">Dynamic List</span><span style=\"color:#4f5b66;\">&quot;&lt;/h2&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;p&gt;&quot;</span><span style=\"color:#a3be8c;\">Use this pattern if the rows in your list will change.</span><span style=\"color:#4f5b66;\">&quot;&lt;/p&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;DynamicList initial_length=</span><span style=\"color:#d08770;\">5</span><span style=\"color:#4f5b66;\">/&gt;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#a7adba;\">/// A list of counters, without the ability\n</span><span style=\"color:#a7adba;\">/// to add or remove any.\n</span><span style=\"color:#4f5b66;\">#[</span><span style=\"color:#bf616a;\">component</span><span style=\"color:#4f5b66;\">]\n</span><span style=\"color:#b48ead;\">fn </span><span style=\"color:#8fa1b3;\">StaticList</span><span style=\"color:#4f5b66;\">(\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">/// How many counters to include in this list.\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#bf616a;\">length</span><span style=\"color:#4f5b66;\">: </span><span style=\"color:#b48ead;\">usize</span><span style=\"color:#4f5b66;\">,\n</span><span style=\"color:#4f5b66;\">) -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// create counter signals that start at incrementing numbers\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> counters = (</span><span style=\"color:#d08770;\">1</span><span style=\"color:#4f5b66;\">..=length).</span><span style=\"color:#96b5b4;\">map</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">idx</span><span style=\"color:#4f5b66;\">| </span><span style=\"color:#96b5b4;\">create_signal</span><span style=\"color:#4f5b66;\">(idx));\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// when you have a list that doesn&#39;t change, you can\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// manipulate it using ordinary Rust iterators\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// and collect it into a Vec&lt;_&gt; to insert it into the DOM\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> counter_buttons = counters\n</span><span style=\"color:#4f5b66;\">        .</span><span style=\"color:#96b5b4;\">map</span><span style=\"color:#4f5b66;\">(|(</span><span style=\"color:#bf616a;\">count</span><span style=\"color:#4f5b66;\">, </span><span style=\"color:#bf616a;\">set_count</span><span style=\"color:#4f5b66;\">)| {\n</span><span style=\"color:#4f5b66;\">            view! {\n</span><span style=\"color:#4f5b66;\">                &lt;li&gt;\n</span><span style=\"color:#4f5b66;\">                    &lt;button\n</span><span style=\"color:#4f5b66;\">                        on:click=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| set_count.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">n</span><span style=\"color:#4f5b66;\">| *n += </span><span style=\"color:#d08770;\">1</span><span style=\"color:#4f5b66;\">)\n</span><span style=\"color:#4f5b66;\">                    &gt;\n</span><span style=\"color:#4f5b66;\">                        {count}\n</span><span style=\"color:#4f5b66;\">                    &lt;/button&gt;\n</span><span style=\"color:#4f5b66;\">                &lt;/li&gt;\n</span><span style=\"color:#4f5b66;\">            }\n</span><span style=\"color:#4f5b66;\">        })\n</span><span style=\"color:#4f5b66;\">        .collect::&lt;Vec&lt;_&gt;&gt;();\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// Note that if `counter_buttons` were a reactive list\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// and its value changed, this would be very inefficient:\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// it would rerender every row every time the list changed.\n</span><span style=\"color:#4f5b66;\">    view! {\n</span><span style=\"color:#4f5b66;\">        &lt;ul&gt;{counter_buttons}&lt;/ul&gt;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#a7adba;\">/// A list of counters that allows you to add or\n</span><span style=\"color:#a7adba;\">/// remove counters.\n</span><span style=\"color:#4f5b66;\">#[</span><span style=\"color:#bf616a;\">component</span><span style=\"color:#4f5b66;\">]\n</span><span style=\"color:#b48ead;\">fn </span><span style=\"color:#8fa1b3;\">DynamicList</span><span style=\"color:#4f5b66;\">(\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">/// The number of counters to begin with.\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#bf616a;\">initial_length</span><span style=\"color:#4f5b66;\">: </span><span style=\"color:#b48ead;\">usize</span><span style=\"color:#4f5b66;\">,\n</span><span style=\"color:#4f5b66;\">) -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// This dynamic list will use the &lt;For/&gt; component.\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// &lt;For/&gt; is a keyed list. This means that each row\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// has a defined key. If the key does not change, the row\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// will not be re-rendered. When the list changes, only\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// the minimum number of changes will be made to the DOM.\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// `next_counter_id` will let us generate unique IDs\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// we do this by simply incrementing the ID by one\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// each time we create a counter\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let mut</span><span style=\"color:#4f5b66;\"> next_counter_id = initial_length;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// we generate an initial list as in &lt;StaticList/&gt;\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// but this time we include the ID along with the signal\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> initial_counters = (</span><span style=\"color:#d08770;\">0</span><span style=\"color:#4f5b66;\">..initial_length)\n</span><span style=\"color:#4f5b66;\">        .</span><span style=\"color:#96b5b4;\">map</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">id</span><span style=\"color:#4f5b66;\">| (id, </span><span style=\"color:#96b5b4;\">create_signal</span><span style=\"color:#4f5b66;\">(id + </span><span style=\"color:#d08770;\">1</span><span style=\"color:#4f5b66;\">)))\n</span><span style=\"color:#4f5b66;\">        .collect::&lt;Vec&lt;_&gt;&gt;();\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// now we store that initial list in a signal\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// this way, we&#39;ll be able to modify the list over time,\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#a7adba;\">// adding and removing counters, and it will change reactively\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let </span><span style=\"color:#4f5b66;\">(counters, set_counters) = </span><span style=\"color:#96b5b4;\">create_signal</span><span style=\"color:#4f5b66;\">(initial_counters);\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> add_counter = </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| {\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// create a signal for the new counter\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> sig = </span><span style=\"color:#96b5b4;\">create_signal</span><span style=\"color:#4f5b66;\">(next_counter_id + </span><span style=\"color:#d08770;\">1</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// add this counter to the list of counters\n</span><span style=\"color:#4f5b66;\">        set_counters.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|counters| {\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#a7adba;\">// since `.update()` gives us `&amp;mut T`\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#a7adba;\">// we can just use normal Vec methods like `push`\n</span><span style=\"color:#4f5b66;\">            counters.</span><span style=\"color:#96b5b4;\">push</span><span style=\"color:#4f5b66;\">((next_counter_id, sig))\n</span><span style=\"color:#4f5b66;\">        });\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#a7adba;\">// increment the ID so it&#39;s always unique\n</span><span style=\"color:#4f5b66;\">        next_counter_id += </span><span style=\"color:#d08770;\">1</span><span style=\"color:#4f5b66;\">;\n</span><span style=\"color:#4f5b66;\">    };\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    view! {\n</span><span style=\"color:#4f5b66;\">        &lt;div&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;button on:click=add_counter&gt;\n</span><span style=\"color:#4f5b66;\">                &quot;</span><span style=\"color:#a3be8c;\">Add Counter</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">            &lt;/button&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;ul&gt;\n</span><span style=\"color:#4f5b66;\">                </span><span style=\"color:#a7adba;\">// The &lt;For/&gt; component is central here\n</span><span style=\"color:#4f5b66;\">                </span><span style=\"color:#a7adba;\">// This allows for efficient, key list rendering\n</span><span style=\"color:#4f5b66;\">                &lt;For\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#a7adba;\">// `each` takes any function that returns an iterator\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#a7adba;\">// this should usually be a signal or derived signal\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#a7adba;\">// if it&#39;s not reactive, just render a Vec&lt;_&gt; instead of &lt;For/&gt;\n</span><span style=\"color:#4f5b66;\">                    each=counters\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#a7adba;\">// the key should be unique and stable for each row\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#a7adba;\">// using an index is usually a bad idea, unless your list\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#a7adba;\">// can only grow, because moving items around inside the list\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#a7adba;\">// means their indices will change and they will all rerender\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#8fa1b3;\">key</span><span style=\"color:#4f5b66;\">=|</span><span style=\"color:#bf616a;\">counter</span><span style=\"color:#4f5b66;\">| counter.</span><span style=\"color:#d08770;\">0\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#a7adba;\">// `children` receives each item from your `each` iterator\n</span><span style=\"color:#4f5b66;\">                    </span><span style=\"color:#a7adba;\">// and returns a view\n</span><span style=\"color:#4f5b66;\">                    children=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|(id, (count, set_count))| {\n</span><span style=\"color:#4f5b66;\">                        view! {\n</span><span style=\"color:#4f5b66;\">                            &lt;li&gt;\n</span><span style=\"color:#4f5b66;\">                                &lt;button\n</span><span style=\"color:#4f5b66;\">                                    on:click=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| set_count.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">n</span><span style=\"color:#4f5b66;\">| *n += </span><span style=\"color:#d08770;\">1</span><span style=\"color:#4f5b66;\">)\n</span><span style=\"color:#4f5b66;\">                                &gt;\n</span><span style=\"color:#4f5b66;\">                                    {count}\n</span><span style=\"color:#4f5b66;\">                                &lt;/button&gt;\n</span><span style=\"color:#4f5b66;\">                                &lt;button\n</span><span style=\"color:#4f5b66;\">                                    on:click=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| {\n</span><span style=\"color:#4f5b66;\">                                        set_counters.</span><span style=\"color:#96b5b4;\">update</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">counters</span><span style=\"color:#4f5b66;\">| {\n</span><span style=\"color:#4f5b66;\">                                            counters.</span><span style=\"color:#96b5b4;\">retain</span><span style=\"color:#4f5b66;\">(|(</span><span style=\"color:#bf616a;\">counter_id</span><span style=\"color:#4f5b66;\">, _)| counter_id != &amp;id)\n</span><span style=\"color:#4f5b66;\">                                        });\n</span><span style=\"color:#4f5b66;\">                                    }\n</span><span style=\"color:#4f5b66;\">                                &gt;\n</span><span style=\"color:#4f5b66;\">                                    &quot;</span><span style=\"color:#a3be8c;\">Remove</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">                                &lt;/button&gt;\n</span><span style=\"color:#4f5b66;\">                            &lt;/li&gt;\n</span><span style=\"color:#4f5b66;\">                        }\n</span><span style=\"color:#4f5b66;\">                    }\n</span><span style=\"color:#4f5b66;\">                /&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;/ul&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;/div&gt;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span></pre>\n",
-            code: pack_example(iteration::showcase),
-            css: stylist::style!("").unwrap(),
-            description: "iterate to render list and collections of items",
-            motivation: "<p>strategies to show repetitions and lists of items.\nThis example illustrates static and dynamic iteration.</p>\n",
-            related: Some(
-                "<p><a href=\"https://leptos-rs.github.io/leptos/view/04_iteration.html\">https://leptos-rs.github.io/leptos/view/04_iteration.html</a></p>\n",
-            ),
-        },
-        Example {
-            name: "dynamic_font",
-            source: "use leptos::*;\nuse leptos::html::Select;\n\nstatic FONTS: [&str; 4] = [\n    \"verdana\",\n    \"times\",\n    \"sans-serif\",\n    \"monospace\",\n];\n\n#[component]\nfn FontSelector(set_font_index: WriteSignal<usize>) -> impl IntoView {\n\n    let select_ref = create_node_ref::<Select>();\n\n    let font_options = FONTS\n    .into_iter()\n    .map(|x| view!{ <option value=x.clone()>{x}</option> })\n    .collect_view();\n\n\n    view!{\n        <label for=\"fonts\">Choose a font:</label>\n        <select name=\"fonts\" id=\"fonts\"\n            ref=select_ref\n            on:change=move |_| set_font_index(\n                select_ref.get().unwrap().selected_index() as usize\n                )\n        >\n            {font_options}\n        </select>\n\n    }\n}\n\npub fn showcase() -> impl IntoView {\n    let (font_index, set_font_index) = create_signal(0usize);\n    view!{\n        <FontSelector set_font_index=set_font_index/>\n        <p\n        style:font-family=move || FONTS[font_index()]>\n            Here is how your font looks like\n        </p>\n    }\n}\n",
-            highlighted_source: "<pre style=\"background-color:#eff1f5;\">\n<span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">leptos::*;\n</span><span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">leptos::html::Select;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">static </span><span style=\"color:#d08770;\">FONTS</span><span style=\"color:#4f5b66;\">: [&amp;</span><span style=\"color:#b48ead;\">str</span><span style=\"color:#4f5b66;\">; </span><span style=\"color:#d08770;\">4</span><span style=\"color:#4f5b66;\">] = [\n</span><span style=\"color:#4f5b66;\">    &quot;</span><span style=\"color:#a3be8c;\">verdana</span><span style=\"color:#4f5b66;\">&quot;,\n</span><span style=\"color:#4f5b66;\">    &quot;</span><span style=\"color:#a3be8c;\">times</span><span style=\"color:#4f5b66;\">&quot;,\n</span><span style=\"color:#4f5b66;\">    &quot;</span><span style=\"color:#a3be8c;\">sans-serif</span><span style=\"color:#4f5b66;\">&quot;,\n</span><span style=\"color:#4f5b66;\">    &quot;</span><span style=\"color:#a3be8c;\">monospace</span><span style=\"color:#4f5b66;\">&quot;,\n</span><span style=\"color:#4f5b66;\">];\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">#[</span><span style=\"color:#bf616a;\">component</span><span style=\"color:#4f5b66;\">]\n</span><span style=\"color:#b48ead;\">fn </span><span style=\"color:#8fa1b3;\">FontSelector</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#bf616a;\">set_font_index</span><span style=\"color:#4f5b66;\">: WriteSignal&lt;</span><span style=\"color:#b48ead;\">usize</span><span style=\"color:#4f5b66;\">&gt;) -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> select_ref = create_node_ref::&lt;Select&gt;();\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> font_options = </span><span style=\"color:#d08770;\">FONTS\n</span><span style=\"color:#4f5b66;\">    .</span><span style=\"color:#96b5b4;\">into_iter</span><span style=\"color:#4f5b66;\">()\n</span><span style=\"color:#4f5b66;\">    .</span><span style=\"color:#96b5b4;\">map</span><span style=\"color:#4f5b66;\">(|</span><span style=\"color:#bf616a;\">x</span><span style=\"color:#4f5b66;\">| view!{ &lt;option value=x.</span><span style=\"color:#96b5b4;\">clone</span><span style=\"color:#4f5b66;\">()&gt;{x}&lt;/option&gt; })\n</span><span style=\"color:#4f5b66;\">    .</span><span style=\"color:#96b5b4;\">collect_view</span><span style=\"color:#4f5b66;\">();\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    view!{\n</span><span style=\"color:#4f5b66;\">        &lt;label </span><span style=\"color:#b48ead;\">for</span><span style=\"color:#4f5b66;\">=&quot;</span><span style=\"color:#a3be8c;\">fonts</span><span style=\"color:#4f5b66;\">&quot;&gt;Choose a font:&lt;</span><span style=\"background-color:#bf616a;color:#eff1f5;\">/</span><span style=\"color:#4f5b66;\">label&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;select name=&quot;</span><span style=\"color:#a3be8c;\">fonts</span><span style=\"color:#4f5b66;\">&quot; id=&quot;</span><span style=\"color:#a3be8c;\">fonts</span><span style=\"color:#4f5b66;\">&quot;\n</span><span style=\"color:#4f5b66;\">            </span><span style=\"color:#b48ead;\">ref</span><span style=\"color:#4f5b66;\">=select_ref\n</span><span style=\"color:#4f5b66;\">            on:change=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| </span><span style=\"color:#96b5b4;\">set_font_index</span><span style=\"color:#4f5b66;\">(\n</span><span style=\"color:#4f5b66;\">                select_ref.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">unwrap</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">selected_index</span><span style=\"color:#4f5b66;\">() as </span><span style=\"color:#b48ead;\">usize\n</span><span style=\"color:#4f5b66;\">                )\n</span><span style=\"color:#4f5b66;\">        &gt;\n</span><span style=\"color:#4f5b66;\">            {font_options}\n</span><span style=\"color:#4f5b66;\">        &lt;/select&gt;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">showcase</span><span style=\"color:#4f5b66;\">() -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let </span><span style=\"color:#4f5b66;\">(font_index, set_font_index) = </span><span style=\"color:#96b5b4;\">create_signal</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">0</span><span style=\"color:#b48ead;\">usize</span><span style=\"color:#4f5b66;\">);\n</span><span style=\"color:#4f5b66;\">    view!{\n</span><span style=\"color:#4f5b66;\">        &lt;FontSelector set_font_index=set_font_index/&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;p\n</span><span style=\"color:#4f5b66;\">        style:font-family=</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| </span><span style=\"color:#d08770;\">FONTS</span><span style=\"color:#4f5b66;\">[</span><span style=\"color:#96b5b4;\">font_index</span><span style=\"color:#4f5b66;\">()]&gt;\n</span><span style=\"color:#4f5b66;\">            Here is how your font looks like\n</span><span style=\"color:#4f5b66;\">        &lt;/p&gt;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span></pre>\n",
-            code: pack_example(dynamic_font::showcase),
-            css: stylist::style!("").unwrap(),
-            description: "This component let you chose a font, and render some text with the according font dynamically.\n",
-            motivation: "<p>This code shows two things:</p>\n<ul>\n<li>how you can use dynamic styles.</li>\n<li>how you can use <code>select</code></li>\n</ul>\n",
-            related: Some(
-                "<p>See <a href=\"#select\">select</a> for the <code>select</code> html component</p>\n<p>For dynamic styles, see <a href=\"https://leptos-rs.github.io/leptos/view/02_dynamic_attributes.html?highlight=dynamic%20st#dynamic-styles\">here</a></p>\n",
-            ),
-        },
-        Example {
-            name: "counter_url_query",
-            source: "use leptos::*;\nuse leptos_router::*;\n\n#[component]\npub fn SimpleQueryCounter() -> impl IntoView {\n    let (count, set_count) = create_query_signal::<i32>(\"count\");\n    let clear = move |_| set_count.set(None);\n    let decrement =\n        move |_| set_count.set(Some(count.get().unwrap_or(0) - 1));\n    let increment =\n        move |_| set_count.set(Some(count.get().unwrap_or(0) + 1));\n\n    view! {\n        <div>\n            <button on:click=clear>\"Clear\"</button>\n            <button on:click=decrement>\"-1\"</button>\n            <span>\"Value: \" {move || count.get().unwrap_or(0)} \"!\"</span>\n            <button on:click=increment>\"+1\"</button>\n        </div>\n    }\n}\n\npub fn showcase() -> impl IntoView {\n    view!{\n        <Router>\n            <SimpleQueryCounter/>\n        </Router>\n    }\n}\n",
-            highlighted_source: "<pre style=\"background-color:#eff1f5;\">\n<span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">leptos::*;\n</span><span style=\"color:#b48ead;\">use </span><span style=\"color:#4f5b66;\">leptos_router::*;\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">#[</span><span style=\"color:#bf616a;\">component</span><span style=\"color:#4f5b66;\">]\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">SimpleQueryCounter</span><span style=\"color:#4f5b66;\">() -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let </span><span style=\"color:#4f5b66;\">(count, set_count) = create_query_signal::&lt;</span><span style=\"color:#b48ead;\">i32</span><span style=\"color:#4f5b66;\">&gt;(&quot;</span><span style=\"color:#a3be8c;\">count</span><span style=\"color:#4f5b66;\">&quot;);\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> clear = </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| set_count.</span><span style=\"color:#96b5b4;\">set</span><span style=\"color:#4f5b66;\">(None);\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> decrement =\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| set_count.</span><span style=\"color:#96b5b4;\">set</span><span style=\"color:#4f5b66;\">(Some(count.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">unwrap_or</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">0</span><span style=\"color:#4f5b66;\">) - </span><span style=\"color:#d08770;\">1</span><span style=\"color:#4f5b66;\">));\n</span><span style=\"color:#4f5b66;\">    </span><span style=\"color:#b48ead;\">let</span><span style=\"color:#4f5b66;\"> increment =\n</span><span style=\"color:#4f5b66;\">        </span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|_| set_count.</span><span style=\"color:#96b5b4;\">set</span><span style=\"color:#4f5b66;\">(Some(count.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">unwrap_or</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">0</span><span style=\"color:#4f5b66;\">) + </span><span style=\"color:#d08770;\">1</span><span style=\"color:#4f5b66;\">));\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#4f5b66;\">    view! {\n</span><span style=\"color:#4f5b66;\">        &lt;div&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;button on:click=clear&gt;&quot;</span><span style=\"color:#a3be8c;\">Clear</span><span style=\"color:#4f5b66;\">&quot;&lt;/button&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;button on:click=decrement&gt;&quot;</span><span style=\"color:#a3be8c;\">-1</span><span style=\"color:#4f5b66;\">&quot;&lt;/button&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;span&gt;&quot;</span><span style=\"color:#a3be8c;\">Value: </span><span style=\"color:#4f5b66;\">&quot; {</span><span style=\"color:#b48ead;\">move </span><span style=\"color:#4f5b66;\">|| count.</span><span style=\"color:#96b5b4;\">get</span><span style=\"color:#4f5b66;\">().</span><span style=\"color:#96b5b4;\">unwrap_or</span><span style=\"color:#4f5b66;\">(</span><span style=\"color:#d08770;\">0</span><span style=\"color:#4f5b66;\">)} &quot;</span><span style=\"color:#a3be8c;\">!</span><span style=\"color:#4f5b66;\">&quot;&lt;/span&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;button on:click=increment&gt;&quot;</span><span style=\"color:#a3be8c;\">+1</span><span style=\"color:#4f5b66;\">&quot;&lt;/button&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;/div&gt;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span><span style=\"color:#4f5b66;\">\n</span><span style=\"color:#b48ead;\">pub fn </span><span style=\"color:#8fa1b3;\">showcase</span><span style=\"color:#4f5b66;\">() -&gt; impl IntoView {\n</span><span style=\"color:#4f5b66;\">    view!{\n</span><span style=\"color:#4f5b66;\">        &lt;Router&gt;\n</span><span style=\"color:#4f5b66;\">            &lt;SimpleQueryCounter/&gt;\n</span><span style=\"color:#4f5b66;\">        &lt;/Router&gt;\n</span><span style=\"color:#4f5b66;\">    }\n</span><span style=\"color:#4f5b66;\">}\n</span></pre>\n",
-            code: pack_example(counter_url_query::showcase),
-            css: stylist::style!("").unwrap(),
-            description: "Constructs a signal synchronized with a specific URL query parameter.",
-            motivation: "<p>The function creates a bidirectional sync mechanism between the state encapsulated in a signal and a URL query parameter. This means that any change to the state will update the URL, and vice versa, making the function especially useful for maintaining state consistency across page reloads.</p>\n",
-            related: Some(
-                "<p>The simpler example is <a href=\"#counter\">counter</a></p>\n<p>look at the <a href=\"https://leptos-rs.github.io/leptos/router/18_params_and_queries.html?highlight=use_que#params-and-queries\">leptos documentation on params and queries</a>\nFor the <code>create_query_signal</code> documentation, see <a href=\"https://docs.rs/leptos_router/latest/leptos_router/fn.create_query_signal.html\">here</a></p>\n",
-            ),
-        },
-    ]
-        .into_iter()
-        .map(|e| (e.name, std::rc::Rc::new(e)))
-        .collect()
-}
@@ -0,0 +1,61 @@
+use leptos::*;
+
+use std::io::{Cursor, Write};
+
+use wasm_bindgen::{JsCast, JsValue};
+use zip::write::{FileOptions, ZipWriter};
+
+use super::{ProjectFile, SourceFile};
+
+/// the exported project as a zip archive,
+/// with everything inside a directory named after the example
+fn project_zip(name: &str, project: &[ProjectFile], files: &[SourceFile]) -> zip::result::ZipResult<Vec<u8>> {
+    let all_files = project.iter()
+        .map(|f| (f.path, f.contents))
+        .chain(files.iter().map(|f| (f.project_path, f.source)));
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    for (path, contents) in all_files {
+        zip.start_file(format!("{name}/{path}"), FileOptions::default())?;
+        zip.write_all(contents.as_bytes())?;
+    }
+    Ok(zip.finish()?.into_inner())
+}
+
+/// makes the browser save `bytes` in a file
+fn download(file_name: &str, bytes: &[u8]) -> Result<(), JsValue> {
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let mut options = web_sys::BlobPropertyBag::new();
+    options.type_("application/zip");
+    let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options)?;
+
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+    let link: web_sys::HtmlAnchorElement = document()
+        .create_element("a")?
+        .unchecked_into();
+    link.set_href(&url);
+    link.set_download(file_name);
+    link.click();
+    web_sys::Url::revoke_object_url(&url)
+}
+
+/// a button to download the example as a standalone trunk project
+#[component]
+pub fn DownloadProject(
+    name: &'static str,
+    project: &'static [ProjectFile],
+    files: &'static [SourceFile],
+    ) -> impl IntoView {
+    let on_click = move |_| {
+        match project_zip(name, project, files) {
+            Ok(bytes) => if let Err(e) = download(&format!("{name}.zip"), &bytes) {
+                logging::error!("could not download the project: {e:?}");
+            },
+            Err(e) => logging::error!("could not create the archive: {e}"),
+        }
+    };
+
+    view!{
+        <button on:click=on_click>"Download project"</button>
+    }
+}
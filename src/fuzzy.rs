@@ -1,18 +1,82 @@
-pub use fuzzy_matcher::skim::SkimMatcherV2;
-
 use leptos::*;
 use leptos::html::Input;
 
+use crate::ranking::{Field, Match, Ranking, Weights};
+use crate::query::{Condition, Query, Term, take_conditions};
+use crate::search_index::SearchIndex;
+use std::collections::HashSet;
+
+/// how many lines are shown before and after the line
+/// where a source matched
+const CONTEXT_LINES: usize = 1;
+
+/// `text`, with the characters at `indices` emphasized
+#[component]
+fn Emphasized(text: String, indices: Vec<usize>) -> impl IntoView {
+    // consecutive characters with the same emphasis are grouped
+    let mut runs: Vec<(bool, String)> = Vec::new();
+    for (i, c) in text.chars().enumerate() {
+        let matched = indices.contains(&i);
+        match runs.last_mut() {
+            Some((m, run)) if *m == matched => run.push(c),
+            _ => runs.push((matched, c.to_string())),
+        }
+    }
+    runs.into_iter()
+        .map(|(matched, run)| if matched {
+            view!{<mark class="fuzzy-match">{run}</mark>}.into_view()
+        } else {
+            run.into_view()
+        })
+        .collect_view()
+}
+
+/// the line of `source` that matched, with the lines around it
+#[component]
+fn SourceSnippet(source: String, found: Match) -> impl IntoView {
+    let line = found.line(&source);
+    let shown = line.saturating_sub(CONTEXT_LINES).max(1)..=line + CONTEXT_LINES;
+    // the position of the first character of each line in the source
+    let mut start = 0;
+    let lines = source.split('\n')
+        .enumerate()
+        .map(|(i, text)| {
+            let line_start = start;
+            start += text.chars().count() + 1;
+            (i + 1, line_start, text)
+        })
+        .filter(|(n, _, _)| shown.contains(n))
+        .map(|(n, line_start, text)| {
+            let indices = found.indices.iter()
+                .filter_map(|i| i.checked_sub(line_start))
+                .collect();
+            view!{
+                <span class="snippet-line" class:matching=n == line>
+                    <span class="snippet-line-number">{n}</span>
+                    <Emphasized text=text.to_string() indices=indices/>
+                </span>
+            }
+        })
+        .collect_view();
+    view!{<pre class="search-snippet">{lines}</pre>}
+}
+
 #[component]
 fn ExampleMatch(
     name: String,
     description: String,
+    /// why the item is shown
+    found: Match,
+    /// the source file that matched, if it is one
+    source: Option<String>,
     highlighted: bool,
     ) -> impl IntoView {
-    // TODO: highlight `matches` in description
+    let indices_in = |field| if found.field == field { found.indices.clone() } else { Vec::new() };
     view!{
             <div style:background-color=highlighted.then(|| "gray")> 
-                <b>{name}</b><p>{description}</p>
+                <b><Emphasized text=name indices=indices_in(Field::Name)/></b>
+                <p><Emphasized text=description indices=indices_in(Field::Description)/></p>
+                {source.map(|source| view!{<SourceSnippet source=source found=found.clone()/>})}
             </div>
         }
 }
@@ -20,23 +84,48 @@ fn ExampleMatch(
 pub trait FuzzyAble {
     fn description(&self) -> String;
     fn name(&self) -> String;
-    fn score(&self, matcher: &SkimMatcherV2, request: &str) -> Option<i64>;
+    /// the source file with this index
+    fn source(&self, file: usize) -> String;
+    /// whether the item has the tag, API, feature, level or text of `term`
+    fn has(&self, term: &Term) -> bool;
+    /// the name, description and sources, where a `Term::Text` is looked for
+    fn text(&self) -> String;
+    /// the sources are only read if `sources` is true
+    fn score(&self, ranking: &Ranking, request: &str, sources: bool) -> Option<Match>;
 }
 
 #[component]
 pub fn FuzzyFinder<I: FuzzyAble + Clone + 'static, F> (
     /// the items to research into
     items: Vec<I>,
-    /// the setter for the index of the item chosen by the user
+    /// the setter for the index of the item chosen by the user,
+    /// and where it matched
     choice: F,
+    /// for each item, whether it can be found.
+    /// All of them can if it is not given
+    #[prop(optional, into)]
+    filter: Option<Signal<Vec<bool>>>,
     focus: RwSignal<bool>,
     placeholder: &'static str,
+    /// how much each field of the items counts in their ranking
+    #[prop(optional)]
+    weights: Weights,
+    /// the words of the items, found by their names.
+    /// Only the sources of the items it gives are fuzzy matched.
+    /// All of them are if it is not given
+    #[prop(optional, into)]
+    index: Option<SearchIndex<'static>>,
     ) 
     -> impl IntoView 
-where F: Fn(usize) + 'static
+where F: Fn((usize, Match)) + 'static
 {
     // word written by the user
     let (request, set_request) = create_signal(String::new());
+    // the conditions the user has finished writing, shown as chips
+    let chips = create_rw_signal(Vec::<Condition>::new());
+    let query = create_memo(move |_|
+        chips.with(|chips| with!(|request| Query::parse(request).with(chips)))
+    );
 
     let input_ref = create_node_ref::<Input>();
 
@@ -55,6 +144,7 @@ where F: Fn(usize) + 'static
                 .blur()
                 .unwrap();
             set_request(String::new());
+            chips.set(Vec::new());
         }
 
     });
@@ -64,15 +154,52 @@ where F: Fn(usize) + 'static
 
     let len = snippets.with_value(|s| s.len());
 
+    // the lowercased texts of the items, only made for the first `Term::Text`
+    let texts = std::rc::Rc::new(std::cell::OnceCell::<Vec<String>>::new());
+
 
 
     let scores: Memo<Vec<_>> = create_memo(move |_| {
-        let matcher = SkimMatcherV2::default();
-        with!(|snippets| 
-             snippets.into_iter()
-            .map(|item| with!(|request| item.score(&matcher, request)))
+        let ranking = Ranking::new(weights);
+        let visible = filter.map(|f| f.get());
+        with!(|snippets, query| {
+            // without any candidate, only the names and descriptions are fuzzy matched:
+            // reading every source is what the index avoids
+            let candidates = index.map(|index| index.candidates(&query.text));
+            // for each condition, the items that may have its text
+            let may_have: Vec<_> = query.conditions.iter()
+                .map(|c| match (&c.term, index) {
+                    (Term::Text(text), Some(index)) => Some(index.may_contain(text)),
+                    _ => None,
+                })
+                .collect();
+            let has = |i: usize, item: &I, condition: &Condition, may_have: &Option<HashSet<&str>>| match (&condition.term, may_have) {
+                (Term::Text(_), Some(m)) if !m.contains(item.name().as_str()) => false,
+                (Term::Text(text), _) => {
+                    let texts = texts.get_or_init(|| snippets.iter().map(|s| s.text().to_lowercase()).collect());
+                    texts[i].contains(&text.to_lowercase())
+                },
+                (term, _) => item.has(term),
+            };
+            snippets.into_iter()
+            .enumerate()
+            .map(|(i, item)| match &visible {
+                Some(v) if !v[i] => None,
+                // the filters are applied before the fuzzy matching
+                _ if !query.conditions.iter()
+                    .zip(&may_have)
+                    .all(|(c, m)| has(i, item, c, m) != c.excluded) => None,
+                // only filters: every item left is a result
+                _ if query.text.is_empty() => Some(Match { score: 0, field: Field::Name, indices: Vec::new() }),
+                _ => {
+                    let sources = candidates.as_ref()
+                        .map(|c| c.contains(item.name().as_str()))
+                        .unwrap_or(true);
+                    item.score(&ranking, &query.text, sources)
+                },
+            })
             .collect()
-            )
+            })
         }
     );
 
@@ -80,12 +207,9 @@ where F: Fn(usize) + 'static
     // the indices of the snippets, but sorted
     // according to the match
     let ordered_matches : Memo<Vec<_>> = create_memo(move |_| {
-        let mut result : Vec<(usize, i64)> = (0..len)
-            .filter_map(|i| match scores()[i]{
-                Some(s) => Some((i, s)),
-                _ => None
-            })
-            .collect();
+        let mut result : Vec<(usize, i64)> = scores.with(|scores| (0..len)
+            .filter_map(|i| scores[i].as_ref().map(|m| (i, m.score)))
+            .collect());
 
         result.sort_by_key(|(_, score)| -score);
         result.into_iter()
@@ -93,9 +217,10 @@ where F: Fn(usize) + 'static
             .collect()
     });
 
-    let confirm = Signal::derive(
-        move || choice(ordered_matches()[highlighted()])
-    );
+    let confirm = Signal::derive(move || {
+        let i = ordered_matches()[highlighted()];
+        choice((i, scores.with(|s| s[i].clone()).unwrap()))
+    });
 
     // view of the matchs
     let match_list = Signal::derive(move || {
@@ -103,24 +228,58 @@ where F: Fn(usize) + 'static
         ordered_matches()
         .into_iter()
         .enumerate()
-        .map(|(i, snippet_id)| view!{<ExampleMatch 
+        .map(|(i, snippet_id)| {
+            let found = scores.with(|s| s[snippet_id].clone()).unwrap();
+            let source = match found.field {
+                Field::Source(file) => Some(snippets[snippet_id].source(file)),
+                _ => None,
+            };
+            view!{<ExampleMatch 
             name=snippets[snippet_id].name()
             description=snippets[snippet_id].description()
+            found=found
+            source=source
             highlighted={highlighted()==i}
             on:mouseover=move |_| highlight(i)
             on:mousedown=move |_| {confirm(); focus.set(false)}
-            />})
+            />}
+        })
         .collect_view()
     });
 
 
     view!{
         <div class="searchbar" style="position: relative">
+            {move || chips.get()
+                .into_iter()
+                .enumerate()
+                .map(|(i, chip)| view!{
+                    <span class="query-chip">
+                        {chip.to_string()}
+                        // on mousedown, so that the search bar keeps the focus
+                        <button
+                            title="remove this filter"
+                            on:mousedown=move |ev| {
+                                ev.prevent_default();
+                                chips.update(|c| { c.remove(i); });
+                            }
+                        >
+                            "×"
+                        </button>
+                    </span>
+                })
+                .collect_view()
+            }
             <input type="text"
                 ref=input_ref
                 placeholder=placeholder
+                title="filters: tag:async api:create_resource feature:nightly level:beginner \"a phrase\" -excluded"
                 on:input=move |ev| {
-                set_request(event_target_value(&ev));
+                let (finished, rest) = take_conditions(&event_target_value(&ev));
+                if !finished.is_empty() {
+                    chips.update(|c| c.extend(finished));
+                }
+                set_request(rest);
                 focus.set(true);
             }
                 on:focusout=move |_| focus.set(false)
@@ -129,6 +288,10 @@ where F: Fn(usize) + 'static
                     if ev.key() == "Escape" {
                         focus.set(false)
                     }
+                    // removes the last chip
+                    if ev.key() == "Backspace" && request.with(String::is_empty) {
+                        chips.update(|c| { c.pop(); });
+                    }
                     if focus.get(){
                         match ev.key().as_ref() {
                             "Enter" => {
@@ -151,9 +314,9 @@ where F: Fn(usize) + 'static
                 prop:value=request
             />
             // results are hidden if the search bar is not focused,
-            // or if no text is written
+            // or if no text or filter is written
             <div style="position:absolute; background-color: white">
-            {move || (!request().is_empty()).then(match_list)}
+            {move || (!query.with(Query::is_empty)).then(match_list)}
             </div>
         </div>
     }
@@ -1,30 +1,222 @@
 use leptos::*;
 use leptos_router::*;
 
-mod examples;
-use examples::{examples, N_EXAMPLES};
+/// generated by build.rs
+mod examples {
+    include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+}
+use examples::{examples, LEARNING_PATH, SEARCH_INDEX};
 
 use std::rc::Rc;
 
 mod fuzzy;
-use fuzzy::{FuzzyFinder, FuzzyAble, SkimMatcherV2};
+use fuzzy::{FuzzyFinder, FuzzyAble};
+
+mod ranking;
+use ranking::{Ranking, Match, Field};
+
+mod query;
+use query::Term;
+
+// shared with build.rs, that builds the index
+#[allow(dead_code)]
+mod search_index;
+use search_index::SearchIndex;
+
+#[cfg(test)]
+#[allow(dead_code)]
+#[path = "../build/analysis.rs"]
+mod analysis;
+
+mod sidebar;
+use sidebar::{Sidebar, Filter};
+
+mod export;
+use export::DownloadProject;
+
+mod anchor;
+use anchor::Anchor;
+
+mod api;
+use api::{ApiChips, ApiIndex, API_INDEX};
 
 use getrandom::getrandom;
 
 use stylist::Style;
+use wasm_bindgen::JsCast;
 
 static PUBLIC_DIR: &str = "leptos-by-example";
 
+/// one rust file of an example
+#[derive(Clone)]
+struct SourceFile {
+    /// relative to the example directory
+    pub path: &'static str,
+    /// relative to the root of the exported project
+    pub project_path: &'static str,
+    pub source: &'static str,
+    /// of the highlighted html, relative to the website
+    pub highlighted_url: &'static str,
+}
+
+/// a file generated by build.rs to run the example on its own
+#[derive(Clone)]
+struct ProjectFile {
+    pub path: &'static str,
+    pub contents: &'static str,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Difficulty {
+    Beginner,
+    Intermediate,
+    Advanced,
+}
+
+impl Difficulty {
+    const ALL: [Difficulty; 3] = [
+        Difficulty::Beginner,
+        Difficulty::Intermediate,
+        Difficulty::Advanced
+    ];
+
+    /// the name used in the `example.toml` files
+    fn name(self) -> &'static str {
+        match self {
+            Difficulty::Beginner => "beginner",
+            Difficulty::Intermediate => "intermediate",
+            Difficulty::Advanced => "advanced",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.name()==name)
+    }
+}
+
+/// the colors of the highlighted code.
+/// build.rs generates a stylesheet for each of them in `themes/`
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CodeTheme {
+    Light,
+    Dark,
+}
+
+impl CodeTheme {
+    const STORAGE_KEY: &'static str = "leptos-by-example-code-theme";
+
+    fn name(self) -> &'static str {
+        match self {
+            CodeTheme::Light => "light",
+            CodeTheme::Dark => "dark",
+        }
+    }
+
+    fn toggled(self) -> Self {
+        match self {
+            CodeTheme::Light => CodeTheme::Dark,
+            CodeTheme::Dark => CodeTheme::Light,
+        }
+    }
+
+    /// the theme the reader chose the last time
+    fn load() -> Self {
+        window()
+            .local_storage()
+            .ok()
+            .flatten()
+            .and_then(|storage| storage.get_item(Self::STORAGE_KEY).ok().flatten())
+            .filter(|name| name=="dark")
+            .map_or(CodeTheme::Light, |_| CodeTheme::Dark)
+    }
+
+    fn save(self) {
+        if let Ok(Some(storage)) = window().local_storage() {
+            if storage.set_item(Self::STORAGE_KEY, self.name()).is_err() {
+                logging::error!("could not save the code theme");
+            }
+        }
+    }
+}
+
+#[component]
+fn ThemeSwitch(theme: RwSignal<CodeTheme>) -> impl IntoView {
+    view!{
+        <button class="theme-switch" on:click=move |_| theme.update(|t| *t = t.toggled())>
+            {move || format!("{} code", theme.get().toggled().name())}
+        </button>
+    }
+}
+
+/// a group of examples in the sidebar,
+/// declared in `examples/categories.toml`
+struct Category {
+    pub name: &'static str,
+    pub title: &'static str,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ApiKind {
+    Function,
+    Struct,
+    Enum,
+    TypeAlias,
+    Trait,
+    Macro,
+    Attribute,
+    Component,
+}
+
+impl ApiKind {
+    fn name(self) -> &'static str {
+        match self {
+            ApiKind::Function => "function",
+            ApiKind::Struct => "struct",
+            ApiKind::Enum => "enum",
+            ApiKind::TypeAlias => "type alias",
+            ApiKind::Trait => "trait",
+            ApiKind::Macro => "macro",
+            ApiKind::Attribute => "attribute macro",
+            ApiKind::Component => "component",
+        }
+    }
+}
+
+/// a leptos item, found by build.rs in the sources of the examples
+struct Api {
+    pub name: &'static str,
+    /// the crate that documents it
+    pub krate: &'static str,
+    pub kind: ApiKind,
+    /// the names of the examples that use it
+    pub examples: &'static [&'static str],
+}
+
 #[derive(Clone)]
 struct Example {
     pub name: &'static str,
-    pub source: &'static str,
-    pub highlighted_source: &'static str,
+    /// the entrypoint first
+    pub files: &'static [SourceFile],
     pub code: Signal<View>,
     pub css: Style,
     pub description: &'static str,
     pub motivation: &'static str,
     pub related: Option<&'static str>,
+    pub features: &'static [&'static str],
+    pub dependencies: &'static [&'static str],
+    pub links: &'static [&'static str],
+    /// what to add to `Cargo.toml` to run the example
+    pub cargo_setup: &'static str,
+    /// `Cargo.toml`, `index.html`... of the exported project.
+    /// The sources are in `files`
+    pub project: &'static [ProjectFile],
+    pub category: Option<&'static str>,
+    pub tags: &'static [&'static str],
+    pub difficulty: Option<Difficulty>,
+    /// the names of the leptos items it uses, see `Api`
+    pub apis: &'static [&'static str],
+    /// only built with `LBE_DRAFTS=1`
+    pub draft: bool,
 }
 
 impl FuzzyAble for Rc<Example> {
@@ -36,17 +228,37 @@ impl FuzzyAble for Rc<Example> {
         self.description.to_string()
     }
 
-    fn score(&self, matcher: &SkimMatcherV2, request: &str) -> Option<i64> {
-        if let Some(s) = matcher.fuzzy(self.name, request, true){
-            return Some(s.0);
-        }
-        if let Some(s) = matcher.fuzzy(self.description, request, true){
-            return Some(s.0);
-        }
-        if let Some(s) = matcher.fuzzy(self.source, request, true){
-            return Some(s.0);
+    fn source(&self, file: usize) -> String {
+        self.files[file].source.to_string()
+    }
+
+    fn has(&self, term: &Term) -> bool {
+        let named = |names: &[&str], name: &str| names.iter().any(|n| n.eq_ignore_ascii_case(name));
+        match term {
+            Term::Tag(tag) => named(self.tags, tag),
+            Term::Api(api) => named(self.apis, api),
+            Term::Feature(feature) => named(self.features, feature),
+            Term::Level(level) => self.difficulty.is_some_and(|d| d.name().eq_ignore_ascii_case(level)),
+            Term::Text(text) => self.text().to_lowercase().contains(&text.to_lowercase()),
         }
-        None
+    }
+
+    fn text(&self) -> String {
+        [self.name, self.description].into_iter()
+            .chain(self.files.iter().map(|f| f.source))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn score(&self, ranking: &Ranking, request: &str, sources: bool) -> Option<Match> {
+        let fields = [(Field::Name, self.name), (Field::Description, self.description)]
+            .into_iter()
+            .chain(self.files.iter()
+                .enumerate()
+                .filter(|_| sources)
+                .map(|(i, f)| (Field::Source(i), f.source))
+            );
+        ranking.rank(request, fields)
     }
 }
 
@@ -72,10 +284,176 @@ fn Documentation<'a>(example: &'a Example) -> impl IntoView {
             <h3>See also</h3>
             <div inner_html=example.related>
             </div>
+            <ul>
+                {example.links.iter()
+                    .map(|l| view!{<li><a href=format!("#{l}")>{*l}</a></li>})
+                    .collect_view()
+                }
+            </ul>
+            <h3>APIs used here</h3>
+            <ApiChips apis=example.apis/>
+            <CargoSetup example=example/>
+        </div>
+    }
+}
+
+#[component]
+fn CargoSetup<'a>(example: &'a Example) -> impl IntoView {
+    view!{
+        <div class="cargo-setup">
+            <h3>Cargo setup</h3>
+            {(!example.features.is_empty()).then(||
+                view!{<p>"leptos features: " {example.features.join(", ")}</p>}
+            )}
+            {(!example.dependencies.is_empty()).then(||
+                view!{<p>"extra crates: " {example.dependencies.join(", ")}</p>}
+            )}
+            <p>add this to the <code>[dependencies]</code> of your <code>Cargo.toml</code>:</p>
+            <pre>{example.cargo_setup}</pre>
+            <DownloadProject
+                name=example.name
+                project=example.project
+                files=example.files
+            />
         </div>
     }
 }
 
+/// the highlighted html of a file, written by build.rs next to the website
+async fn fetch_highlighted(url: &'static str) -> Result<String, String> {
+    let response = reqwasm::http::Request::get(url)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.ok() {
+        return Err(format!("{url}: {}", response.status_text()));
+    }
+    response.text().await.map_err(|e| e.to_string())
+}
+
+/// shows the first selected line,
+/// or else the line after the `lbe:focus` marker of the example
+fn scroll_code(lines: Option<(usize, usize)>) {
+    match lines {
+        Some((start, _)) => if let Some(line) = document().get_element_by_id(&format!("L{start}")) {
+            // the line may be in a hidden region
+            if let Ok(Some(fold)) = line.closest("details") {
+                let _ = fold.set_attribute("open", "");
+            }
+            line.scroll_into_view();
+        },
+        // only the code panel scrolls, the page stays where it is
+        None => if let Ok(Some(line)) = document().query_selector(".code-snippet .focus") {
+            if let (Ok(Some(snippet)), Some(line)) = (line.closest(".code-snippet"), line.dyn_ref::<web_sys::HtmlElement>()) {
+                snippet.set_scroll_top(line.offset_top());
+            }
+        },
+    }
+}
+
+/// the code of an example,
+/// with a tab for each file if there are several of them.
+///
+/// Clicking a line number links to that line,
+/// and shift-clicking another one to the lines in between.
+/// The `// NOTE:` comments are shown as side notes,
+/// unless the reader prefers them inline
+#[component]
+fn SourceFiles(
+    name: &'static str,
+    files: &'static [SourceFile],
+    anchor: Memo<Option<Anchor>>
+    ) -> impl IntoView {
+    let (selected, select) = create_signal(0);
+    let highlighted = create_local_resource(selected, move |i| fetch_highlighted(files[i].highlighted_url));
+
+    // the file the url points into, if it points to lines or to a file
+    let anchored_file = move || anchor.get()
+        .filter(|a| a.file.is_some() || a.lines.is_some())
+        .map(|a| a.file
+            .and_then(|f| files.iter().position(|x| x.path == f))
+            .unwrap_or(0)
+        );
+    create_effect(move |_| if let Some(i) = anchored_file() {
+        select(i)
+    });
+    let selected_lines = create_memo(move |_| {
+        (anchored_file() == Some(selected()))
+            .then(|| anchor.get().and_then(|a| a.lines))
+            .flatten()
+    });
+
+    // only scroll when the lines come from a link, not from a click
+    let scroll = store_value(true);
+    create_effect(move |_| {
+        if let Some(Ok(_)) = highlighted.get() {
+            let lines = selected_lines.get();
+            if scroll.get_value() {
+                request_animation_frame(move || scroll_code(lines));
+            }
+        }
+        scroll.set_value(true);
+    });
+
+    let has_notes = files.iter().any(|f| f.source.contains("// NOTE:"));
+    let annotated = create_rw_signal(true);
+
+    let navigate = use_navigate();
+    let select_line = move |ev: ev::MouseEvent| {
+        let line = event_target::<web_sys::Element>(&ev)
+            .get_attribute("data-line")
+            .and_then(|n| n.parse::<usize>().ok());
+        if let Some(n) = line {
+            let lines = match selected_lines.get_untracked() {
+                Some((start, _)) if ev.shift_key() => (start.min(n), start.max(n)),
+                _ => (n, n),
+            };
+            let i = selected.get_untracked();
+            let file = (i > 0).then(|| files[i].path);
+            scroll.set_value(false);
+            navigate(
+                &format!("{PUBLIC_DIR}/#{}", Anchor::lines(name, file, lines).to_hash()),
+                NavigateOptions { scroll: false, ..Default::default() }
+            );
+        }
+    };
+
+    view!{
+        <div class="code-snippet" class:annotated=annotated on:click=select_line>
+            {has_notes.then(|| view!{
+                <button class="notes-toggle" on:click=move |_| annotated.update(|a| *a = !*a)>
+                    {move || if annotated.get() { "notes as comments" } else { "notes on the side" }}
+                </button>
+            })}
+            {move || selected_lines.get().map(|(start, end)| view!{
+                <style>
+                    {(start..=end).map(|n| format!(".code-snippet #L{n}")).collect::<Vec<_>>().join(", ")}
+                    " { background: rgba(255, 200, 0, 0.25); }"
+                </style>
+            })}
+            {(files.len() > 1).then(|| view!{
+                <nav class="file-tree">
+                    {files.iter().enumerate()
+                        .map(|(i, f)| view!{
+                            <button
+                                class:selected=move || selected()==i
+                                on:click=move |_| select(i)
+                            >
+                                {f.path}
+                            </button>
+                        })
+                        .collect_view()
+                    }
+                </nav>
+            })}
+            {move || match highlighted.get() {
+                None => view!{<p>"loading..."</p>}.into_view(),
+                Some(Ok(html)) => view!{<div inner_html=html></div>}.into_view(),
+                Some(Err(e)) => view!{<p>"could not load the code: " {e}</p>}.into_view(),
+            }}
+        </div>
+    }
+}
 
 fn random_small_int(n: usize) -> usize {
     let buf: &mut [u8] = &mut [0,0];
@@ -92,19 +470,42 @@ fn Suggestions(
     n: usize
     ) -> impl IntoView {
     view!{
-        <button on:click=move |_| choice(examples[random_small_int(n)].name)>
-            random example
-        </button>
-        <button class="suggestion" on:click=move |_| choice("hello_world")>
-            hello world
-        </button>
+        {(n > 0).then(|| view!{
+            <button on:click=move |_| choice(examples[random_small_int(n)].name)>
+                random example
+            </button>
+        })}
+        {LEARNING_PATH.first().map(|first| view!{
+            <button class="suggestion" on:click=move |_| choice(first)>
+                start learning
+            </button>
+        })}
     }
 }
 
+/// "previous" and "next" links along the learning path
+#[component]
+fn LessonNavigation(name: String) -> impl IntoView {
+    LEARNING_PATH.iter()
+        .position(|x| *x==name)
+        .map(|i| view!{
+            <span class="lesson">
+                {(i > 0).then(|| view!{
+                    <a href=format!("#{}", LEARNING_PATH[i-1])>"previous"</a>
+                })}
+                <span>"lesson " {i+1} " of " {LEARNING_PATH.len()}</span>
+                {LEARNING_PATH.get(i+1).map(|next| view!{
+                    <a href=format!("#{next}")>"next"</a>
+                })}
+            </span>
+        })
+}
+
 #[component]
 fn ExampleView<F,I, 'a> (
     example: Option<&'a Example>,
     name: String,
+    anchor: Memo<Option<Anchor>>,
     fallback: F
     ) -> impl IntoView 
     where F: Fn(String) -> I + 'static,
@@ -114,7 +515,7 @@ fn ExampleView<F,I, 'a> (
     match example {
         Some(e) => view!{
             // the code
-            <div class="code-snippet" inner_html=e.highlighted_source></div>
+            <SourceFiles name=e.name files=e.files anchor=anchor/>
             // the in-browser demo
             <div class="demo">
                 <div class=e.css.get_class_name().to_string()>{e.code}</div>
@@ -130,14 +531,25 @@ fn App(examples: examples::Examples,
        default: &'static str
     ) -> impl IntoView {
 
+    // a smaller gallery may not have the default example:
+    // it starts with its learning path, or with its first example
+    let default = match examples.contains_key(default) {
+        true => default,
+        false => LEARNING_PATH.first().copied()
+            .or_else(|| examples.keys().copied().min())
+            .unwrap_or(default),
+    };
+
     let location = use_location();
-    let current_name = move ||
+    let anchor = create_memo(move |_|
         match &location.hash.get().chars().collect::<Vec<_>>()[..] {
             [] => None,
-            ['#'] => Some(default.to_string()),
-            ['#', rest @ ..] => Some(rest.into_iter().collect()),
+            ['#'] => Some(Anchor::parse(default)),
+            ['#', rest @ ..] => Some(Anchor::parse(&rest.iter().collect::<String>())),
             _ => unreachable!()
-    };
+    });
+    // selecting lines changes the url, but must not render the example again
+    let current_name = create_memo(move |_| anchor.get().map(|a| a.name));
 
     let searchbar_focus = create_rw_signal(false);
 
@@ -158,40 +570,110 @@ fn App(examples: examples::Examples,
 
     let names : Vec<_> = examples.keys().cloned().collect();
 
-    let set_name = Callback::new(move |i| {
-        set_current_name(names[i])
+    // a match in the code opens the example at the matching line
+    let set_name = Callback::new({
+        let examples = examples.clone();
+        let navigate = leptos_router::use_navigate();
+        move |(i, found): (usize, Match)| {
+            let name = names[i];
+            match found.field {
+                Field::Source(file) => {
+                    let file = &examples[name].files[file];
+                    let line = found.line(file.source);
+                    navigate(
+                        &format!("{PUBLIC_DIR}/#{}", Anchor::lines(name, Some(file.path), (line, line)).to_hash()),
+                        Default::default()
+                    )
+                },
+                _ => set_current_name(name),
+            }
+        }
+    });
+
+    let filter = Filter::new();
+    let visible = create_memo({
+        let examples_list = examples_list.clone();
+        move |_| examples_list.iter()
+            .map(|e| filter.accepts(e))
+            .collect::<Vec<_>>()
     });
 
+    let theme = create_rw_signal(CodeTheme::load());
+    create_effect(move |_| theme.get().save());
+
     view!{
         <h1 class="title">Leptos by example</h1>
+        <ThemeSwitch theme=theme/>
+        <div class=move || format!("layout theme-{}", theme.get().name())>
+        <Sidebar examples=examples_list.clone() filter=filter/>
         <div class="container" clone:names>
             <FuzzyFinder 
                 placeholder="type `s` or click here to search example"
                 items=examples_list.clone()
+                filter=visible
+                index=SEARCH_INDEX
                 focus=searchbar_focus
                 choice=set_name
             />
             {move || match current_name(){
+                Some(name) if name == API_INDEX => view!{
+                    <div class="example-title">
+                        <b>"API index"</b>
+                    </div>
+                    <ApiIndex anchor=anchor/>
+                }.into_view(),
                 Some(name) => view!{
-                    <b class="example-title">{name.clone()}</b>
+                    <div class="example-title">
+                        <b>{name.clone()}</b>
+                        {examples.get(name.as_str())
+                            .filter(|e| e.draft)
+                            .map(|_| view!{<span class="draft-badge">"draft"</span>})
+                        }
+                        <LessonNavigation name=name.clone()/>
+                    </div>
                     <ExampleView 
                         example=examples.get(name.as_str()).map(|x| x.as_ref())
                         name=name
+                        anchor=anchor
                         fallback=move |x| view!{<div>example {x} does not exist</div>}
                     />
                 }.into_view(),
-                None => view!{<Suggestions 
-                    choice=set_current_name 
-                    examples=examples_list.clone()
-                    n=N_EXAMPLES/>
-                }.into_view()
+                None => {
+                    // the drafts are not ready to be suggested
+                    let published: Vec<_> = examples_list.iter()
+                        .filter(|e| !e.draft)
+                        .cloned()
+                        .collect();
+                    let n = published.len();
+                    view!{<Suggestions 
+                        choice=set_current_name 
+                        examples=published
+                        n=n/>
+                    }.into_view()
+                }
             }}
         </div>
+        </div>
+    }
+}
+
+/// a page of `pages/` (see `render_page` in build.rs) shows an example without javascript.
+/// The app removes it, and moves to its own url showing the same example
+fn take_over_prerendered() {
+    let Some(page) = document().get_element_by_id("prerendered") else {
+        return
+    };
+    let name = page.get_attribute("data-example").unwrap_or_default();
+    page.remove();
+    // the page's `<base>` is the root of the website, where the app lives
+    if let Ok(history) = window().history() {
+        let _ = history.replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&format!("./#{name}")));
     }
 }
 
 fn main(){
     console_error_panic_hook::set_once();
+    take_over_prerendered();
 
     let entrypoint = move ||
         view!{
@@ -0,0 +1,251 @@
+use std::fmt;
+
+/// what an item must have, or not have, to be a search result
+#[derive(Clone, PartialEq, Debug)]
+pub enum Term {
+    /// `tag:async`
+    Tag(String),
+    /// `api:create_resource`
+    Api(String),
+    /// `feature:nightly`
+    Feature(String),
+    /// `level:beginner`
+    Level(String),
+    /// a quoted phrase, or an excluded word,
+    /// to find as it is in one of the texts of the item
+    Text(String),
+}
+
+impl Term {
+    /// `key:value`, or a word
+    fn of_word(word: &str) -> Self {
+        let filter = match word.split_once(':') {
+            Some((key, value)) if !value.is_empty() => match key {
+                "tag" => Some(Term::Tag(value.to_string())),
+                "api" => Some(Term::Api(value.to_string())),
+                "feature" => Some(Term::Feature(value.to_string())),
+                "level" => Some(Term::Level(value.to_string())),
+                _ => None,
+            },
+            _ => None,
+        };
+        filter.unwrap_or_else(|| Term::Text(word.to_string()))
+    }
+}
+
+/// a term of the query, that may be excluded with a `-`
+#[derive(Clone, PartialEq, Debug)]
+pub struct Condition {
+    pub term: Term,
+    pub excluded: bool,
+}
+
+/// as it is written in the search bar
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.excluded {
+            write!(f, "-")?;
+        }
+        match &self.term {
+            Term::Tag(x) => write!(f, "tag:{x}"),
+            Term::Api(x) => write!(f, "api:{x}"),
+            Term::Feature(x) => write!(f, "feature:{x}"),
+            Term::Level(x) => write!(f, "level:{x}"),
+            Term::Text(x) if self.excluded && !x.contains(char::is_whitespace) => write!(f, "{x}"),
+            Term::Text(x) => write!(f, "\"{x}\""),
+        }
+    }
+}
+
+/// a part of the search request
+#[derive(Clone, PartialEq, Debug)]
+enum Part {
+    /// a word for the fuzzy matching
+    Free(String),
+    Condition(Condition),
+}
+
+/// a part of the search request, and where it is written
+#[derive(Clone, PartialEq, Debug)]
+struct Token {
+    part: Part,
+    /// in bytes
+    start: usize,
+    end: usize,
+    /// whether the user has finished writing it:
+    /// it is followed by a space, or is a closed quote
+    complete: bool,
+}
+
+/// splits the request of the search bar in words, `key:value` filters,
+/// quoted phrases, and their exclusions with a `-` in front
+fn tokens(request: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = request.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue
+        }
+        let excluded = c == '-';
+        if excluded {
+            chars.next();
+        }
+        let quoted = chars.peek().is_some_and(|(_, c)| *c == '"');
+        let (text, end, complete) = if quoted {
+            chars.next();
+            let text_start = chars.peek().map_or(request.len(), |(i, _)| *i);
+            let closing = chars.by_ref().find(|(_, c)| *c == '"');
+            match closing {
+                Some((i, _)) => (&request[text_start..i], i + 1, true),
+                None => (&request[text_start..], request.len(), false),
+            }
+        } else {
+            let text_start = chars.peek().map_or(request.len(), |(i, _)| *i);
+            let space = chars.by_ref().find(|(_, c)| c.is_whitespace());
+            match space {
+                Some((i, _)) => (&request[text_start..i], i, true),
+                None => (&request[text_start..], request.len(), false),
+            }
+        };
+        let part = match (quoted, excluded) {
+            // a lone `-` is still being written
+            (false, true) if text.is_empty() => Part::Free(String::new()),
+            (false, false) => match Term::of_word(text) {
+                Term::Text(word) => Part::Free(word),
+                term => Part::Condition(Condition { term, excluded }),
+            },
+            (false, true) => Part::Condition(Condition { term: Term::of_word(text), excluded }),
+            (true, _) => Part::Condition(Condition { term: Term::Text(text.to_string()), excluded }),
+        };
+        tokens.push(Token { part, start, end, complete });
+    }
+    tokens
+}
+
+/// the search request, parsed before the fuzzy matching
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Query {
+    /// the free words and the quoted phrases, for the fuzzy matching
+    pub text: String,
+    /// every one of them must hold
+    pub conditions: Vec<Condition>,
+}
+
+impl Query {
+    pub fn parse(request: &str) -> Self {
+        let mut words = Vec::new();
+        let mut conditions = Vec::new();
+        for token in tokens(request) {
+            match token.part {
+                Part::Free(word) => words.push(word),
+                Part::Condition(condition) => {
+                    if let (Term::Text(phrase), false) = (&condition.term, condition.excluded) {
+                        words.push(phrase.clone());
+                    }
+                    conditions.push(condition);
+                },
+            }
+        }
+        words.retain(|w| !w.is_empty());
+        Query {
+            text: words.join(" "),
+            conditions,
+        }
+    }
+
+    /// adds conditions given apart from the request, like the chips of the search bar
+    pub fn with(mut self, conditions: &[Condition]) -> Self {
+        for condition in conditions {
+            if let (Term::Text(phrase), false) = (&condition.term, condition.excluded) {
+                if !self.text.is_empty() {
+                    self.text.push(' ');
+                }
+                self.text.push_str(phrase);
+            }
+        }
+        self.conditions.splice(0..0, conditions.iter().cloned());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty() && self.conditions.is_empty()
+    }
+}
+
+/// takes the conditions the user has finished writing out of the request,
+/// so that they become chips. Returns them, and the rest of the request
+pub fn take_conditions(request: &str) -> (Vec<Condition>, String) {
+    let mut conditions = Vec::new();
+    let mut rest = String::new();
+    let mut kept_until = 0;
+    for token in tokens(request) {
+        if let (Part::Condition(condition), true) = (token.part, token.complete) {
+            rest.push_str(&request[kept_until..token.start]);
+            kept_until = token.end;
+            conditions.push(condition);
+        }
+    }
+    rest.push_str(&request[kept_until..]);
+    if !conditions.is_empty() {
+        rest = rest.trim_start().to_string();
+    }
+    (conditions, rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn condition(term: Term, excluded: bool) -> Condition {
+        Condition { term, excluded }
+    }
+
+    #[test]
+    fn filters_and_free_text() {
+        let query = Query::parse("tag:async signal level:beginner");
+        assert_eq!(query.text, "signal");
+        assert_eq!(query.conditions, vec![
+            condition(Term::Tag("async".into()), false),
+            condition(Term::Level("beginner".into()), false),
+        ]);
+    }
+
+    #[test]
+    fn phrases_and_exclusions() {
+        let query = Query::parse("\"set interval\" -api:view -timer -\"two words\"");
+        assert_eq!(query.text, "set interval");
+        assert_eq!(query.conditions, vec![
+            condition(Term::Text("set interval".into()), false),
+            condition(Term::Api("view".into()), true),
+            condition(Term::Text("timer".into()), true),
+            condition(Term::Text("two words".into()), true),
+        ]);
+    }
+
+    #[test]
+    fn unknown_keys_are_words() {
+        let query = Query::parse("std::mem tag:");
+        assert_eq!(query.text, "std::mem tag:");
+        assert!(query.conditions.is_empty());
+    }
+
+    #[test]
+    fn finished_conditions_are_taken() {
+        let (conditions, rest) = take_conditions("tag:async resource -feat");
+        assert_eq!(conditions, vec![condition(Term::Tag("async".into()), false)]);
+        assert_eq!(rest, "resource -feat");
+        // the condition being written stays in the search bar
+        let (conditions, rest) = take_conditions("\"create res");
+        assert!(conditions.is_empty());
+        assert_eq!(rest, "\"create res");
+    }
+
+    #[test]
+    fn conditions_are_shown_as_written() {
+        for written in ["tag:async", "-api:view", "-timer", "\"set interval\"", "-\"two words\""] {
+            let (conditions, _) = take_conditions(&format!("{written} "));
+            assert_eq!(conditions[0].to_string(), written);
+        }
+    }
+}
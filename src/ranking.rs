@@ -0,0 +1,234 @@
+use fuzzy_matcher::skim::SkimMatcherV2;
+
+/// the text of an item that a request matched
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Field {
+    Name,
+    Description,
+    /// the source file with this index
+    Source(usize),
+}
+
+/// how well an item matches a request, and where
+#[derive(Clone, PartialEq, Debug)]
+pub struct Match {
+    pub score: i64,
+    /// the field that counted the most in the score
+    pub field: Field,
+    /// the positions of the matched characters in the field, counted in `char`s
+    pub indices: Vec<usize>,
+}
+
+impl Match {
+    /// the line of `text` with the most matched characters, starting at 1
+    pub fn line(&self, text: &str) -> usize {
+        let line_of = |index| text.chars().take(index).filter(|c| *c == '\n').count() + 1;
+        let mut counts = std::collections::BTreeMap::new();
+        for index in &self.indices {
+            *counts.entry(line_of(*index)).or_insert(0) += 1;
+        }
+        // the first one on a tie
+        counts.into_iter()
+            .max_by_key(|(line, count)| (*count, std::cmp::Reverse(*line)))
+            .map_or(1, |(line, _)| line)
+    }
+}
+
+/// how much a match in each field counts
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Weights {
+    pub name: f64,
+    pub description: f64,
+    /// for the best matching file only,
+    /// so that examples with many files are not favored
+    pub source: f64,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Weights {
+            name: 3.0,
+            description: 2.0,
+            source: 1.0,
+        }
+    }
+}
+
+impl Weights {
+    fn of(&self, field: Field) -> f64 {
+        match field {
+            Field::Name => self.name,
+            Field::Description => self.description,
+            Field::Source(_) => self.source,
+        }
+    }
+}
+
+/// added to the score of a field that has the request as a whole word
+const EXACT_WORD_BONUS: f64 = 1.0;
+/// added to the score of a field with a word starting with the request
+const PREFIX_BONUS: f64 = 0.5;
+/// the scores are compared as integers
+const SCORE_SCALE: f64 = 1000.0;
+
+/// scores an item on all of its fields, each on the same scale
+pub struct Ranking {
+    matcher: SkimMatcherV2,
+    weights: Weights,
+}
+
+impl Ranking {
+    pub fn new(weights: Weights) -> Self {
+        Ranking {
+            matcher: SkimMatcherV2::default(),
+            weights,
+        }
+    }
+
+    /// how well `text` matches `request`: the fuzzy score,
+    /// relative to the one of a perfect match so that it is between 0 and 1,
+    /// and the bonuses for whole words
+    fn field_score(&self, text: &str, request: &str) -> Option<(f64, Vec<usize>)> {
+        let (score, indices) = self.matcher.fuzzy(text, request, true)?;
+        let perfect = self.matcher.fuzzy(request, request, false)
+            .map_or(1, |(s, _)| s)
+            .max(1);
+        let mut total = (score as f64 / perfect as f64).clamp(0.0, 1.0);
+
+        let request = request.trim().to_lowercase();
+        // identifiers are words, `set_interval` included
+        let words = text
+            .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .map(str::to_lowercase);
+        let mut prefix = false;
+        for word in words {
+            if word == request {
+                return Some((total + EXACT_WORD_BONUS, indices))
+            }
+            prefix |= word.starts_with(&request);
+        }
+        if prefix {
+            total += PREFIX_BONUS;
+        }
+        Some((total, indices))
+    }
+
+    /// the sum of the weighted scores of the `fields` of an item,
+    /// or nothing if none of them matches
+    pub fn rank<'a>(&self, request: &str, fields: impl IntoIterator<Item = (Field, &'a str)>) -> Option<Match> {
+        let mut total = 0.0;
+        let mut best_source = 0.0_f64;
+        // the field shown as the reason of the match
+        let mut best: Option<(f64, Field, Vec<usize>)> = None;
+        for (field, text) in fields {
+            let Some((score, indices)) = self.field_score(text, request) else {
+                continue
+            };
+            let weighted = score * self.weights.of(field);
+            match field {
+                Field::Source(_) => best_source = best_source.max(weighted),
+                _ => total += weighted,
+            }
+            let better = match &best {
+                Some((s, _, _)) => weighted > *s,
+                None => true,
+            };
+            if better {
+                best = Some((weighted, field, indices));
+            }
+        }
+        best.map(|(_, field, indices)| Match {
+            score: ((total + best_source) * SCORE_SCALE) as i64,
+            field,
+            indices,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// name, description and sources of a few examples
+    const CORPUS: &[(&str, &str, &[&str])] = &[
+        ("counter", "A simple counter with two buttons", &["let (count, set_count) = create_signal(0);"]),
+        ("account_balance", "Show the money on a bank account", &["let balance = create_rw_signal(100);"]),
+        ("time_picker", "Choose an hour and minutes", &["<input type=\"time\" on:change=fetch_slots/>"]),
+        ("interval", "A timer that ticks every second", &["set_interval_with_handle(tick, Duration::from_secs(1))"]),
+        ("fetch", "Load cat pictures from an API", &["create_resource(count, fetch_cats)", "mod model;"]),
+    ];
+
+    /// the names of the corpus examples that match, the best first
+    fn ranked(ranking: &Ranking, request: &str) -> Vec<&'static str> {
+        let mut matches: Vec<_> = CORPUS.iter()
+            .filter_map(|(name, description, sources)| {
+                let fields = [(Field::Name, *name), (Field::Description, *description)]
+                    .into_iter()
+                    .chain(sources.iter().enumerate().map(|(i, s)| (Field::Source(i), *s)));
+                ranking.rank(request, fields).map(|m| (*name, m.score))
+            })
+            .collect();
+        matches.sort_by_key(|(_, score)| -score);
+        matches.into_iter().map(|(name, _)| name).collect()
+    }
+
+    #[test]
+    fn exact_name_comes_first() {
+        let ranking = Ranking::new(Weights::default());
+        assert_eq!(ranked(&ranking, "counter").first(), Some(&"counter"));
+    }
+
+    #[test]
+    fn strong_description_beats_weak_name() {
+        let ranking = Ranking::new(Weights::default());
+        let results = ranked(&ranking, "timer");
+        let position = |name| results.iter().position(|n| *n == name).unwrap();
+        assert!(position("interval") < position("time_picker"), "{results:?}");
+    }
+
+    #[test]
+    fn word_prefix_is_boosted() {
+        let ranking = Ranking::new(Weights::default());
+        let results = ranked(&ranking, "count");
+        let position = |name| results.iter().position(|n| *n == name).unwrap();
+        assert!(position("counter") < position("account_balance"), "{results:?}");
+    }
+
+    #[test]
+    fn name_beats_source() {
+        let ranking = Ranking::new(Weights::default());
+        // `fetch` is the name of one example, and starts a word in the source of another
+        assert_eq!(ranked(&ranking, "fetch").first(), Some(&"fetch"));
+    }
+
+    #[test]
+    fn weights_are_configurable() {
+        let sources_first = Ranking::new(Weights { name: 0.1, description: 0.1, source: 10.0 });
+        assert_eq!(ranked(&sources_first, "create_signal").first(), Some(&"counter"));
+        let results = ranked(&sources_first, "count");
+        // `fetch` uses `count` in its code, `account_balance` only in its name
+        let position = |name| results.iter().position(|n| *n == name).unwrap();
+        assert!(position("fetch") < position("account_balance"), "{results:?}");
+    }
+
+    #[test]
+    fn the_best_field_is_reported() {
+        let ranking = Ranking::new(Weights::default());
+        let fields = [(Field::Name, "interval"), (Field::Source(0), "set_interval_with_handle(tick)")];
+        let found = ranking.rank("set_interval_with_handle", fields).unwrap();
+        assert_eq!(found.field, Field::Source(0));
+        assert_eq!(found.line("set_interval_with_handle(tick)"), 1);
+    }
+
+    #[test]
+    fn no_match() {
+        let ranking = Ranking::new(Weights::default());
+        assert!(ranked(&ranking, "zzzz").is_empty());
+    }
+
+    #[test]
+    fn line_with_most_matches() {
+        let found = Match { score: 0, field: Field::Source(0), indices: vec![0, 6, 7, 8] };
+        assert_eq!(found.line("a\nbc\nxyz\n"), 3);
+    }
+}
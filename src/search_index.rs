@@ -0,0 +1,318 @@
+//! the search index of the examples.
+//! build.rs includes this file too: it builds the tables with `build`,
+//! and the app queries them through `SearchIndex`
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+/// the words of a text: identifiers, the words of the comments and of the descriptions.
+/// They are lowercased and lose their underscores, like the words of the requests,
+/// so that `setinterval` finds `set_interval`
+pub fn tokens(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .map(|w| w.replace('_', "").to_lowercase())
+        .filter(|w| !w.is_empty())
+}
+
+/// the groups of three consecutive characters of a token
+fn trigrams(token: &str) -> impl Iterator<Item = String> + '_ {
+    let chars: Vec<_> = token.chars().collect();
+    (0..chars.len().saturating_sub(2))
+        .map(move |i| chars[i..i + 3].iter().collect())
+}
+
+/// the tables of the index, before they are written as static data
+#[derive(Debug, Default)]
+pub struct Tables {
+    pub names: Vec<String>,
+    /// every token, sorted, and the examples that have it
+    pub tokens: Vec<(String, Vec<u16>)>,
+    /// every trigram of the tokens, sorted, and the positions of the tokens that have it
+    pub trigrams: Vec<(String, Vec<u32>)>,
+}
+
+/// the tables of the index of `examples`, given with their tokens
+pub fn build(examples: &BTreeMap<String, BTreeSet<String>>) -> Tables {
+    let mut tokens: BTreeMap<&str, Vec<u16>> = BTreeMap::new();
+    for (i, example_tokens) in examples.values().enumerate() {
+        let i = u16::try_from(i).expect("less than 65536 examples");
+        for token in example_tokens {
+            tokens.entry(token).or_default().push(i);
+        }
+    }
+    let mut trigram_tokens: BTreeMap<String, Vec<u32>> = BTreeMap::new();
+    for (position, token) in tokens.keys().enumerate() {
+        let position = u32::try_from(position).expect("less than 2^32 tokens");
+        for trigram in trigrams(token).collect::<BTreeSet<_>>() {
+            trigram_tokens.entry(trigram).or_default().push(position);
+        }
+    }
+    Tables {
+        names: examples.keys().cloned().collect(),
+        tokens: tokens.into_iter()
+            .map(|(t, ids)| (t.to_string(), ids))
+            .collect(),
+        trigrams: trigram_tokens.into_iter().collect(),
+    }
+}
+
+/// the tables of the index, as generated by build.rs in `examples::SEARCH_INDEX`
+#[derive(Clone, Copy, Debug)]
+pub struct SearchIndex<'a> {
+    /// the examples, numbered as in the other tables
+    pub names: &'a [&'a str],
+    pub tokens: &'a [(&'a str, &'a [u16])],
+    pub trigrams: &'a [(&'a str, &'a [u32])],
+}
+
+impl<'a> SearchIndex<'a> {
+    /// the examples with a token starting with `word`
+    fn with_prefix(&self, word: &str) -> BTreeSet<u16> {
+        let start = self.tokens.partition_point(|(t, _)| *t < word);
+        self.tokens[start..].iter()
+            .take_while(|(t, _)| t.starts_with(word))
+            .flat_map(|(_, ids)| ids.iter().copied())
+            .collect()
+    }
+
+    /// the examples with `word` in the middle of a token.
+    /// The tokens with all of its trigrams are the only ones to check
+    fn with_substring(&self, word: &str) -> BTreeSet<u16> {
+        let mut positions: Option<BTreeSet<u32>> = None;
+        for trigram in trigrams(word) {
+            let found = match self.trigrams.binary_search_by(|(t, _)| (*t).cmp(&trigram)) {
+                Ok(i) => self.trigrams[i].1.iter().copied().collect(),
+                Err(_) => return BTreeSet::new(),
+            };
+            positions = Some(match positions {
+                Some(p) => p.intersection(&found).copied().collect(),
+                None => found,
+            });
+        }
+        positions.unwrap_or_default()
+            .into_iter()
+            .map(|p| self.tokens[p as usize])
+            .filter(|(token, _)| token.contains(word))
+            .flat_map(|(_, ids)| ids.iter().copied())
+            .collect()
+    }
+
+    /// the examples with `word` anywhere in a token
+    fn containing(&self, word: &str) -> BTreeSet<u16> {
+        if word.chars().count() >= 3 {
+            return self.with_substring(word)
+        }
+        // too short for the trigrams
+        self.tokens.iter()
+            .filter(|(token, _)| token.contains(word))
+            .flat_map(|(_, ids)| ids.iter().copied())
+            .collect()
+    }
+
+    /// the names of the examples that may have `text` as it is written:
+    /// each of its words is in one of their tokens.
+    /// Only their texts need to be searched for it
+    pub fn may_contain(&self, text: &str) -> HashSet<&'a str> {
+        let mut examples: Option<BTreeSet<u16>> = None;
+        for word in tokens(text) {
+            let ids = self.containing(&word);
+            examples = Some(match examples {
+                Some(e) => e.intersection(&ids).copied().collect(),
+                None => ids,
+            });
+        }
+        match examples {
+            Some(examples) => examples.into_iter().map(|i| self.names[usize::from(i)]).collect(),
+            // no word, like `::`
+            None => self.names.iter().copied().collect(),
+        }
+    }
+
+    /// the names of the examples that have every word of `request`,
+    /// at the start or in the middle of one of their tokens.
+    /// Words of less than three characters are only found at the start.
+    /// The fuzzy matcher only needs to read the sources of these
+    pub fn candidates(&self, request: &str) -> HashSet<&'a str> {
+        let mut examples: Option<BTreeSet<u16>> = None;
+        for word in tokens(request) {
+            let mut ids = self.with_prefix(&word);
+            ids.extend(self.with_substring(&word));
+            examples = Some(match examples {
+                Some(e) => e.intersection(&ids).copied().collect(),
+                None => ids,
+            });
+        }
+        examples.unwrap_or_default()
+            .into_iter()
+            .map(|i| self.names[usize::from(i)])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ranking::{Field, Ranking, Weights};
+    use std::time::{Duration, Instant};
+
+    /// queries `tables` through a `SearchIndex`, as the app does with the generated one
+    fn with_index<T>(tables: &Tables, f: impl FnOnce(SearchIndex) -> T) -> T {
+        let names: Vec<_> = tables.names.iter().map(String::as_str).collect();
+        fn table<T>(t: &[(String, Vec<T>)]) -> Vec<(&str, &[T])> {
+            t.iter().map(|(t, ids)| (t.as_str(), ids.as_slice())).collect()
+        }
+        let (tokens, trigrams) = (table(&tables.tokens), table(&tables.trigrams));
+        f(SearchIndex { names: &names, tokens: &tokens, trigrams: &trigrams })
+    }
+
+    fn tables_of(examples: &[(&str, &str)]) -> Tables {
+        build(&examples.iter()
+            .map(|(name, text)| (name.to_string(), tokens(text).collect()))
+            .collect())
+    }
+
+    #[test]
+    fn prefixes_and_substrings() {
+        let tables = tables_of(&[
+            ("timer", "let handle = set_interval_with_handle(tick, 1000);"),
+            ("fetch", "/// loads the cats\nlet cats = create_resource(count, fetch_cats);"),
+        ]);
+        with_index(&tables, |index| {
+            assert_eq!(index.candidates("create_res"), HashSet::from(["fetch"]));
+            assert_eq!(index.candidates("interval"), HashSet::from(["timer"]));
+            assert_eq!(index.candidates("LOADS"), HashSet::from(["fetch"]));
+            assert_eq!(index.candidates("let"), HashSet::from(["timer", "fetch"]));
+            assert_eq!(index.candidates("tick cats"), HashSet::new());
+            assert_eq!(index.candidates("nothing"), HashSet::new());
+        });
+    }
+
+    #[test]
+    fn underscores_and_case_are_ignored() {
+        let tables = tables_of(&[("timer", "let handle = set_interval_with_handle(tick, 1000);")]);
+        with_index(&tables, |index| {
+            assert_eq!(index.candidates("setinterval"), HashSet::from(["timer"]));
+            assert_eq!(index.candidates("Set_Interval"), HashSet::from(["timer"]));
+            assert_eq!(index.candidates("interval_with"), HashSet::from(["timer"]));
+        });
+    }
+
+    #[test]
+    fn phrases_anywhere_in_tokens() {
+        let tables = tables_of(&[
+            ("timer", "let handle = set_interval_with_handle(tick, 1000);"),
+            ("fetch", "let cats = create_resource(count, fetch_cats);"),
+        ]);
+        with_index(&tables, |index| {
+            // the end of `set`, and the start of `interval`
+            assert_eq!(index.may_contain("t_inter"), HashSet::from(["timer"]));
+            assert_eq!(index.may_contain("ts = cr"), HashSet::from(["fetch"]));
+            assert_eq!(index.may_contain("cr"), HashSet::from(["fetch"]));
+            assert_eq!(index.may_contain("("), HashSet::from(["timer", "fetch"]));
+            assert_eq!(index.may_contain("tick cats"), HashSet::new());
+        });
+    }
+
+    const WORDS: &[&str] = &[
+        "signal", "create_signal", "create_resource", "view", "component", "button", "counter",
+        "timer", "set_interval_with_handle", "fetch", "suspense", "router", "store_value",
+        "memo", "effect", "children", "callback", "class", "style", "input", "event",
+    ];
+
+    /// the same sequence on every run
+    struct Random(u64);
+
+    impl Random {
+        fn below(&mut self, n: usize) -> usize {
+            self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (self.0 >> 33) as usize % n
+        }
+
+        /// one of `WORDS`, numbered like the items of a real code base: `memo_42`
+        fn identifier(&mut self) -> String {
+            let word = WORDS[self.below(WORDS.len())];
+            format!("{word}_{}", self.below(5000))
+        }
+    }
+
+    /// name, description and source of `n` made up examples of 150 lines
+    fn synthetic_corpus(n: usize) -> Vec<(String, String, String)> {
+        let mut random = Random(42);
+        (0..n)
+            .map(|i| {
+                let name = format!("{}_{i}", WORDS[random.below(WORDS.len())]);
+                let description = format!("How to use {} with {}", random.identifier(), random.identifier());
+                let source = (0..150)
+                    .map(|_| format!(
+                        "    let {} = {}({}); // {}\n",
+                        random.identifier(), random.identifier(), random.identifier(), random.identifier()
+                    ))
+                    .collect();
+                (name, description, source)
+            })
+            .collect()
+    }
+
+    /// the time it takes to rank the corpus for each request,
+    /// reading only the sources of the `candidates` if there are some
+    fn search(
+        corpus: &[(String, String, String)],
+        requests: &[&str],
+        candidates: impl Fn(&str) -> Option<HashSet<String>>,
+    ) -> Duration {
+        let ranking = Ranking::new(Weights::default());
+        let start = Instant::now();
+        for request in requests {
+            let candidates = candidates(request);
+            let results = corpus.iter()
+                .filter_map(|(name, description, source)| {
+                    let read_source = candidates.as_ref().map(|c| c.contains(name)).unwrap_or(true);
+                    let fields = [(Field::Name, name.as_str()), (Field::Description, description.as_str())]
+                        .into_iter()
+                        .chain(read_source.then_some((Field::Source(0), source.as_str())));
+                    ranking.rank(request, fields)
+                })
+                .count();
+            std::hint::black_box(results);
+        }
+        start.elapsed()
+    }
+
+    #[test]
+    #[ignore = "a benchmark: cargo test --release --bins -- --ignored --nocapture search_index"]
+    fn benchmark_synthetic_corpus() {
+        let corpus = synthetic_corpus(1000);
+        // from none, where only the names and descriptions are ranked, to all of them
+        let requests = ["stintrvl", "create_resource_4217", "suspense_250", "callback_12 memo_7", "set_interval", "signal"];
+
+        // as the index sees them
+        let texts: Vec<_> = corpus.iter()
+            .map(|(name, description, source)| (name, format!("{name} {description} {source}").to_lowercase().replace('_', "")))
+            .collect();
+        let tables = build(&texts.iter()
+            .map(|(name, text)| (name.to_string(), tokens(text).collect()))
+            .collect());
+        println!(
+            "index of {} examples: {} tokens, {} trigrams",
+            tables.names.len(), tables.tokens.len(), tables.trigrams.len()
+        );
+
+        with_index(&tables, |index| {
+            for request in requests {
+                // exactly the examples that have every word
+                let expected: HashSet<_> = texts.iter()
+                    .filter(|(_, text)| tokens(request).all(|w| text.contains(&w)))
+                    .map(|(name, _)| name.as_str())
+                    .collect();
+                let candidates = index.candidates(request);
+                assert_eq!(candidates, expected, "{request}");
+                println!("{request:?}: {} candidates", candidates.len());
+            }
+        });
+
+        let scan_time = search(&corpus, &requests, |_| None);
+        let index_time = with_index(&tables, |index| search(&corpus, &requests, |request| {
+            Some(index.candidates(request).into_iter().map(str::to_string).collect())
+        }));
+        println!("full scan: {scan_time:?}, with the index: {index_time:?}");
+    }
+}
@@ -0,0 +1,123 @@
+use leptos::*;
+
+use std::rc::Rc;
+
+use super::api::API_INDEX;
+use super::{Example, Difficulty};
+use super::examples::{CATEGORIES, TAGS};
+
+/// the tag and the difficulty the reader is interested in
+#[derive(Clone, Copy)]
+pub struct Filter {
+    pub tag: RwSignal<Option<&'static str>>,
+    pub difficulty: RwSignal<Option<Difficulty>>,
+}
+
+impl Filter {
+    pub fn new() -> Self {
+        Filter {
+            tag: create_rw_signal(None),
+            difficulty: create_rw_signal(None),
+        }
+    }
+
+    /// whether the example should be shown.
+    /// It reads the signals, so it is reactive
+    pub fn accepts(&self, example: &Example) -> bool {
+        let tag_ok = match self.tag.get() {
+            Some(t) => example.tags.contains(&t),
+            None => true,
+        };
+        let difficulty_ok = match self.difficulty.get() {
+            Some(d) => example.difficulty == Some(d),
+            None => true,
+        };
+        tag_ok && difficulty_ok
+    }
+}
+
+#[component]
+fn ExampleFilter(filter: Filter) -> impl IntoView {
+    view!{
+        <div class="filter">
+            <select on:change=move |ev| {
+                let tag = event_target_value(&ev);
+                filter.tag.set(TAGS.iter().find(|t| **t==tag).copied())
+            }>
+                <option value="">"any tag"</option>
+                {TAGS.iter()
+                    .map(|t| view!{<option value=*t>{*t}</option>})
+                    .collect_view()
+                }
+            </select>
+            <select on:change=move |ev| {
+                filter.difficulty.set(Difficulty::from_name(&event_target_value(&ev)))
+            }>
+                <option value="">"any level"</option>
+                {Difficulty::ALL.iter()
+                    .map(|d| view!{<option value=d.name()>{d.name()}</option>})
+                    .collect_view()
+                }
+            </select>
+        </div>
+    }
+}
+
+/// the list of the examples, grouped by category
+#[component]
+pub fn Sidebar(
+    examples: Vec<Rc<Example>>,
+    filter: Filter,
+    ) -> impl IntoView {
+    let mut examples = examples;
+    examples.sort_by_key(|e| e.name);
+
+    let in_category = |c: Option<&str>| -> Vec<Rc<Example>> {
+        examples.iter()
+            .filter(|e| e.category == c)
+            .cloned()
+            .collect()
+    };
+
+    let groups: Vec<_> = CATEGORIES.iter()
+        .map(|c| (c.title, in_category(Some(c.name))))
+        .chain(std::iter::once(("Other", in_category(None))))
+        .filter(|(_, examples)| !examples.is_empty())
+        .collect();
+
+    view!{
+        <aside class="sidebar">
+            <ExampleFilter filter=filter/>
+            <a href=format!("#{API_INDEX}")>"API index"</a>
+            {groups.into_iter()
+                .map(|(title, examples)| {
+                    let hidden = {
+                        let examples = examples.clone();
+                        move || examples.iter().all(|e| !filter.accepts(e))
+                    };
+                    view!{
+                        <details open style:display=move || hidden().then_some("none")>
+                            <summary>{title}</summary>
+                            <ul>
+                            {examples.into_iter()
+                                .map(|e| {
+                                    let name = e.name;
+                                    let draft = e.draft;
+                                    view!{
+                                        <li style:display=move || (!filter.accepts(&e)).then_some("none")>
+                                            <a href=format!("#{name}")>{name}</a>
+                                            {draft.then(|| view!{<span class="draft-badge">"draft"</span>})}
+                                        </li>
+                                    }
+                                })
+                                .collect_view()
+                            }
+                            </ul>
+                        </details>
+                    }
+                })
+                .collect_view()
+            }
+        </aside>
+    }
+}